@@ -0,0 +1,86 @@
+use std::collections::HashMap;
+
+use util::{ParseError, Solution, Source};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = (Vec<i32>, Vec<i32>);
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        let source = Source::new(data);
+
+        let mut left = Vec::new();
+        let mut right = Vec::new();
+
+        for line in source.lines() {
+            let mut pair = line.split_ascii_whitespace();
+            let first = source.next(&mut pair, line, "two location IDs")?;
+            let second = source.next(&mut pair, first, "a second location ID")?;
+            left.push(source.parse(first, "a location ID")?);
+            right.push(source.parse(second, "a location ID")?);
+        }
+
+        Ok((left, right))
+    }
+
+    fn part1((left, right): &Self::Input) -> i32 {
+        let mut first_list = left.clone();
+        let mut second_list = right.clone();
+
+        first_list.sort();
+        second_list.sort();
+
+        let mut result = 0;
+        for (first, second) in first_list.iter().zip(second_list) {
+            result += (first - second).abs();
+        }
+
+        result
+    }
+
+    fn part2((left, right): &Self::Input) -> i32 {
+        let mut right_count = HashMap::<i32, i32>::new();
+
+        for &r in right {
+            *right_count.entry(r).or_default() += 1;
+        }
+
+        let mut result = 0;
+        for l in left {
+            result += l * (*right_count.entry(*l).or_default())
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "
+        3   4
+        4   3
+        2   5
+        1   3
+        3   9
+        3   3
+    ";
+
+    #[test]
+    fn test_part1() {
+        let input = Day1::parse(INPUT).unwrap();
+
+        assert_eq!(Day1::part1(&input), 11);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = Day1::parse(INPUT).unwrap();
+
+        assert_eq!(Day1::part2(&input), 31);
+    }
+}
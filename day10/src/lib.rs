@@ -0,0 +1,138 @@
+use std::collections::HashSet;
+
+use util::{Grid, ParseError, Pos, Solution};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Grid<u32>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        // Impassable tiles ('.') get a height no trail can ever climb to.
+        Grid::parse_with(data, "a height or '.'", |c| match c {
+            '.' => Some(10),
+            _ => c.to_digit(10),
+        })
+    }
+
+    fn part1(map: &Self::Input) -> u32 {
+        map.find_all(&0).map(|head| {
+            let mut visited = HashSet::new();
+            trail_score(map, head, &mut visited)
+        }).sum()
+    }
+
+    fn part2(map: &Self::Input) -> u32 {
+        map.find_all(&0).map(|head| {
+            trail_rating(map, head)
+        }).sum()
+    }
+}
+
+fn trail_score(map: &Grid<u32>, pos: Pos, visited: &mut HashSet<Pos>) -> u32 {
+    if !visited.insert(pos) {
+        return 0;
+    }
+
+    let curr = map[pos];
+    if curr == 9 {
+        return 1;
+    }
+
+    map.neighbours(pos)
+        .filter(|&next| map[next] == curr + 1)
+        .map(|next| trail_score(map, next, visited))
+        .sum()
+}
+
+fn trail_rating(map: &Grid<u32>, pos: Pos) -> u32 {
+    let curr = map[pos];
+    if curr == 9 {
+        return 1;
+    }
+
+    map.neighbours(pos)
+        .filter(|&next| map[next] == curr + 1)
+        .map(|next| trail_rating(map, next))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "
+        89010123
+        78121874
+        87430965
+        96549874
+        45678903
+        32019012
+        01329801
+        10456732
+    ";
+
+    #[test]
+    fn test_part1() {
+        let input = Day10::parse(INPUT).unwrap();
+
+        assert_eq!(Day10::part1(&input), 36);
+    }
+
+    #[test]
+    fn test_part1_ex1() {
+        let input = "
+            ...0...
+            ...1...
+            ...2...
+            6543456
+            7.....7
+            8.....8
+            9.....9
+        ";
+        let input = Day10::parse(input).unwrap();
+
+        assert_eq!(Day10::part1(&input), 2);
+    }
+
+    #[test]
+    fn test_part1_ex2() {
+        let input = "
+            ..90..9
+            ...1.98
+            ...2..7
+            6543456
+            765.987
+            876....
+            987....
+        ";
+        let input = Day10::parse(input).unwrap();
+
+        assert_eq!(Day10::part1(&input), 4);
+    }
+
+    #[test]
+    fn test_part1_ex3() {
+        let input = "
+            10..9..
+            2...8..
+            3...7..
+            4567654
+            ...8..3
+            ...9..2
+            .....01
+        ";
+        let input = Day10::parse(input).unwrap();
+
+        assert_eq!(Day10::part1(&input), 3);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = Day10::parse(INPUT).unwrap();
+
+        assert_eq!(Day10::part2(&input), 81);
+    }
+}
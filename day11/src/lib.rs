@@ -0,0 +1,75 @@
+use std::collections::HashMap;
+
+use util::{ParseError, Solution, Source};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<u64>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        let source = Source::new(data);
+
+        data.split_ascii_whitespace()
+            .map(|s| source.parse(s, "a stone number"))
+            .collect()
+    }
+
+    fn part1(stones: &Self::Input) -> usize {
+        mutate(stones, 25, &mut HashMap::new())
+    }
+
+    fn part2(stones: &Self::Input) -> usize {
+        mutate(stones, 75, &mut HashMap::new())
+    }
+}
+
+/// Counts the stones after blinking `generations` times. `memory` caches
+/// results per stone and can be reused between calls.
+pub fn mutate(stones: &[u64], generations: usize, memory: &mut HashMap<(u64, usize), usize>) -> usize {
+    stones.iter().map(|s| mutate_stone(*s, generations, memory)).sum()
+}
+
+fn mutate_stone(stone: u64, generations: usize, memory: &mut HashMap<(u64, usize), usize>) -> usize {
+    if let Some(mem) = memory.get(&(stone, generations)) {
+        return *mem;
+    }
+
+    let result = if generations == 0 {
+        1
+    } else if stone == 0 {
+        mutate_stone(1, generations-1, memory)
+    } else if count_digits(stone).is_multiple_of(2) {
+        let s = stone.to_string();
+        let (first, second) = s.split_at(s.len()/2);
+        mutate_stone(first.parse().unwrap(), generations-1, memory) + mutate_stone(second.parse().unwrap(), generations-1, memory)
+    } else {
+        mutate_stone(stone*2024, generations-1, memory)
+    };
+
+    memory.insert((stone, generations), result);
+
+    result
+}
+
+fn count_digits(n: u64) -> u32 {
+    n.checked_ilog10().unwrap_or(0) + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "
+        125 17
+    ";
+
+    #[test]
+    fn test_part1() {
+        let input = Day11::parse(INPUT).unwrap();
+
+        assert_eq!(Day11::part1(&input), 55312);
+    }
+}
@@ -0,0 +1,431 @@
+use std::collections::HashSet;
+
+use util::{ParseError, Solution, Source};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<Vec<char>>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        let source = Source::new(data);
+
+        source.parse_lines(|l| {
+            l.char_indices()
+                .map(|(i, c)| match c {
+                    _ if c.is_ascii_alphabetic() => Ok(c),
+                    _ => Err(source.char_error(l, i, "a plant type")),
+                })
+                .collect()
+        })
+    }
+
+    fn part1(map: &Self::Input) -> u64 {
+        fence_cost(map)
+    }
+
+    fn part2(map: &Self::Input) -> u64 {
+        fence_cost_part2(map)
+    }
+}
+
+/// Total price of fencing every region, as area times perimeter.
+pub fn fence_cost(map: &[Vec<char>]) -> u64 {
+    let mut visited = HashSet::new();
+
+    let mut result = 0;
+    for row in 0..map.len() {
+        for col in 0..map[row].len() {
+            if visited.contains(&(row, col)) {
+                continue;
+            }
+
+            result += find_region(map, (row, col), &mut visited);
+        }
+    }
+    result
+}
+
+fn find_region(map: &[Vec<char>], start: Point, visited: &mut HashSet<Point>) -> u64 {
+    let mut area = 0;
+    let mut perimeter = 0;
+    let mut queue = vec![start];
+    let (row, col) = start;
+    let crop_type = map[row][col];
+
+    while let Some((row, col)) = queue.pop() {
+        if visited.contains(&(row, col)) {
+            continue;
+        }
+
+        if map[row][col] != crop_type {
+            perimeter += 1;
+            continue;
+        }
+
+        visited.insert((row, col));
+
+        area += 1;
+
+        if row == 0 || map[row - 1][col] != crop_type {
+            // Count the top edge
+            perimeter += 1;
+        } else if !visited.contains(&(row - 1, col)) {
+            // Visit the cell above
+            queue.push((row - 1, col));
+        }
+
+        if row == map.len() - 1 || map[row + 1][col] != crop_type {
+            // Count the bottom edge
+            perimeter += 1;
+        } else if !visited.contains(&(row + 1, col)) {
+            // Visit the cell below
+            queue.push((row + 1, col));
+        }
+
+        if col == 0 || map[row][col - 1] != crop_type {
+            // Count the left edge
+            perimeter += 1;
+        } else if !visited.contains(&(row, col - 1)) {
+            // Visit the cell to the left
+            queue.push((row, col - 1));
+        }
+
+        if col == map[row].len() - 1 || map[row][col + 1] != crop_type {
+            // Count the right edge
+            perimeter += 1;
+        } else if !visited.contains(&(row, col + 1)) {
+            // Visit the cell to the right
+            queue.push((row, col + 1));
+        }
+    }
+
+    area as u64 * perimeter as u64
+}
+
+/// Total price of fencing every region, as area times number of sides.
+pub fn fence_cost_part2(map: &[Vec<char>]) -> u64 {
+    let mut visited = HashSet::new();
+
+    let mut result = 0;
+    for row in 0..map.len() {
+        for col in 0..map[row].len() {
+            if visited.contains(&(row, col)) {
+                continue;
+            }
+
+            result += find_region_part2(map, (row, col), &mut visited);
+        }
+    }
+    result
+}
+
+fn find_region_part2(map: &[Vec<char>], start: Point, visited: &mut HashSet<Point>) -> u64 {
+    let mut area = 0;
+    let mut sides = 0;
+    let mut queue = vec![start];
+    let (row, col) = start;
+    let crop_type = map[row][col];
+
+    while let Some((row, col)) = queue.pop() {
+        if visited.contains(&(row, col)) {
+            continue;
+        }
+
+        visited.insert((row, col));
+
+        area += 1;
+
+        let pos = MapPos {
+            map,
+            row,
+            col,
+            crop_type,
+        };
+
+        if pos.north_west_corner() {
+            sides += 1;
+        }
+        if pos.north_east_corner() {
+            sides += 1;
+        }
+        if pos.south_east_corner() {
+            sides += 1;
+        }
+        if pos.south_west_corner() {
+            sides += 1;
+        }
+
+        if let Some(point) = pos.north() {
+            if !visited.contains(&point) {
+                // Visit the cell to the north.
+                queue.push(point);
+            }
+        }
+
+        if let Some(point) = pos.east() {
+            if !visited.contains(&point) {
+                // Visit the cell to the east.
+                queue.push(point);
+            }
+        }
+
+        if let Some(point) = pos.south() {
+            if !visited.contains(&point) {
+                // Visit the cell to the south.
+                queue.push(point);
+            }
+        }
+
+        if let Some(point) = pos.west() {
+            if !visited.contains(&point) {
+                // Visit the cell to the west.
+                queue.push(point);
+            }
+        }
+    }
+
+    area as u64 * sides as u64
+}
+
+struct MapPos<'a> {
+    map: &'a [Vec<char>],
+    row: usize,
+    col: usize,
+    crop_type: char,
+}
+
+impl<'a> MapPos<'a> {
+    fn north(&self) -> Option<Point> {
+        if self.row == 0 || self.map[self.row - 1][self.col] != self.crop_type {
+            None
+        } else {
+            Some((self.row - 1, self.col))
+        }
+    }
+    fn north_east(&self) -> Option<Point> {
+        if self.row == 0
+            || self.col == self.map[self.row].len() - 1
+            || self.map[self.row - 1][self.col + 1] != self.crop_type
+        {
+            None
+        } else {
+            Some((self.row - 1, self.col + 1))
+        }
+    }
+
+    fn east(&self) -> Option<Point> {
+        if self.col == self.map[self.row].len() - 1
+            || self.map[self.row][self.col + 1] != self.crop_type
+        {
+            None
+        } else {
+            Some((self.row, self.col + 1))
+        }
+    }
+
+    fn south_east(&self) -> Option<Point> {
+        if self.row == self.map.len() - 1
+            || self.col == self.map[self.row].len() - 1
+            || self.map[self.row + 1][self.col + 1] != self.crop_type
+        {
+            None
+        } else {
+            Some((self.row + 1, self.col + 1))
+        }
+    }
+
+    fn south(&self) -> Option<Point> {
+        if self.row == self.map.len() - 1 || self.map[self.row + 1][self.col] != self.crop_type {
+            None
+        } else {
+            Some((self.row + 1, self.col))
+        }
+    }
+
+    fn south_west(&self) -> Option<Point> {
+        if self.row == self.map.len() - 1
+            || self.col == 0
+            || self.map[self.row + 1][self.col - 1] != self.crop_type
+        {
+            None
+        } else {
+            Some((self.row + 1, self.col - 1))
+        }
+    }
+
+    fn west(&self) -> Option<Point> {
+        if self.col == 0 || self.map[self.row][self.col - 1] != self.crop_type {
+            None
+        } else {
+            Some((self.row, self.col - 1))
+        }
+    }
+
+    fn north_west(&self) -> Option<Point> {
+        if self.row == 0 || self.col == 0 || self.map[self.row - 1][self.col - 1] != self.crop_type
+        {
+            None
+        } else {
+            Some((self.row - 1, self.col - 1))
+        }
+    }
+
+    fn north_west_corner(&self) -> bool {
+        (
+            // Normal corner
+            self.north().is_none() && self.west().is_none()
+        ) || (
+            // Inverted corner
+            self.north_west().is_none() && self.north().is_some() && self.west().is_some()
+        )
+    }
+
+    fn north_east_corner(&self) -> bool {
+        (
+            // Normal corner
+            self.north().is_none() && self.east().is_none()
+        ) || (
+            // Inverted corner
+            self.north_east().is_none() && self.north().is_some() && self.east().is_some()
+        )
+    }
+
+    fn south_east_corner(&self) -> bool {
+        (
+            // Normal corner
+            self.south().is_none() && self.east().is_none()
+        ) || (
+            // Inverted corner
+            self.south_east().is_none() && self.south().is_some() && self.east().is_some()
+        )
+    }
+
+    fn south_west_corner(&self) -> bool {
+        (
+            // Normal corner
+            self.south().is_none() && self.west().is_none()
+        ) || (
+            // Inverted corner
+            self.south_west().is_none() && self.south().is_some() && self.west().is_some()
+        )
+    }
+}
+
+type Point = (usize, usize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1_ex1() {
+        const INPUT: &str = "
+            AAAA
+            BBCD
+            BBCC
+            EEEC
+        ";
+
+        let input = Day12::parse(INPUT).unwrap();
+
+        assert_eq!(Day12::part1(&input), 140);
+    }
+
+    #[test]
+    fn test_part1_ex2() {
+        const INPUT: &str = "
+            OOOOO
+            OXOXO
+            OOOOO
+            OXOXO
+            OOOOO
+        ";
+
+        let input = Day12::parse(INPUT).unwrap();
+
+        assert_eq!(Day12::part1(&input), 21 * 36 + 4 * 4);
+    }
+
+    #[test]
+    fn test_part1_ex3() {
+        const INPUT: &str = "
+            RRRRIICCFF
+            RRRRIICCCF
+            VVRRRCCFFF
+            VVRCCCJFFF
+            VVVVCJJCFE
+            VVIVCCJJEE
+            VVIIICJJEE
+            MIIIIIJJEE
+            MIIISIJEEE
+            MMMISSJEEE
+        ";
+
+        let input = Day12::parse(INPUT).unwrap();
+
+        assert_eq!(Day12::part1(&input), 1930);
+    }
+
+    #[test]
+    fn test_part2_ex1() {
+        const INPUT: &str = "
+            AAAA
+            BBCD
+            BBCC
+            EEEC
+        ";
+
+        let input = Day12::parse(INPUT).unwrap();
+
+        assert_eq!(Day12::part2(&input), 80);
+    }
+
+    #[test]
+    fn test_part2_ex2() {
+        const INPUT: &str = "
+            OOOOO
+            OXOXO
+            OOOOO
+            OXOXO
+            OOOOO
+        ";
+
+        let input = Day12::parse(INPUT).unwrap();
+
+        assert_eq!(Day12::part2(&input), 436);
+    }
+
+    #[test]
+    fn test_part2_ex3() {
+        const INPUT: &str = "
+            EEEEE
+            EXXXX
+            EEEEE
+            EXXXX
+            EEEEE
+        ";
+
+        let input = Day12::parse(INPUT).unwrap();
+
+        assert_eq!(Day12::part2(&input), 236);
+    }
+
+    #[test]
+    fn test_part2_ex4() {
+        const INPUT: &str = "
+            AAAAAA
+            AAABBA
+            AAABBA
+            ABBAAA
+            ABBAAA
+            AAAAAA
+        ";
+
+        let input = Day12::parse(INPUT).unwrap();
+
+        assert_eq!(Day12::part2(&input), 368);
+    }
+}
@@ -0,0 +1,170 @@
+use util::{ParseError, Solution, Source};
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Machine>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        let source = Source::new(data);
+
+        source.sections()
+            .map(|s| parse_machine(&source, s))
+            .collect()
+    }
+
+    fn part1(machines: &Self::Input) -> i64 {
+        machines.iter()
+            .filter_map(|m| m.win())
+            .sum::<i64>()
+    }
+
+    fn part2(machines: &Self::Input) -> i64 {
+        let mut machines = machines.clone();
+
+        machines.iter_mut()
+            .filter_map(|m| {
+                m.prize.0 += 10_000_000_000_000;
+                m.prize.1 += 10_000_000_000_000;
+                m.win()
+            })
+            .sum::<i64>()
+    }
+}
+
+/// Parses one machine's button and prize description from `block`, which
+/// must be a slice of `source`.
+pub fn parse_machine<'a>(source: &Source<'a>, block: &'a str) -> Result<Machine, ParseError> {
+    let mut lines = util::lines(block);
+
+    let line = source.next(&mut lines, block, "a 'Button A' line")?;
+    let (dx, dy) = parse_pair(source, line, "Button A: X+", "Y+")?;
+    let a = Button { dx, dy, cost: 3 };
+
+    let line = source.next(&mut lines, line, "a 'Button B' line")?;
+    let (dx, dy) = parse_pair(source, line, "Button B: X+", "Y+")?;
+    let b = Button { dx, dy, cost: 1 };
+
+    let line = source.next(&mut lines, line, "a 'Prize' line")?;
+    let prize = parse_pair(source, line, "Prize: X=", "Y=")?;
+
+    Ok(Machine { a, b, prize })
+}
+
+fn parse_pair<'a>(source: &Source<'a>, line: &'a str, x: &str, y: &str) -> Result<(i64, i64), ParseError> {
+    let (s1, s2) = source.split_once(line, ", ")?;
+    let x = source.parse(source.strip_prefix(s1, x)?, "a number")?;
+    let y = source.parse(source.strip_prefix(s2, y)?, "a number")?;
+
+    Ok((x, y))
+}
+
+/// A claw machine with two buttons and a prize location.
+#[derive(Debug, Clone)]
+pub struct Machine {
+    a: Button,
+    b: Button,
+    prize: (i64, i64),
+}
+
+#[derive(Debug, Clone)]
+struct Button {
+    dx: i64,
+    dy: i64,
+    cost: i64,
+}
+
+impl Machine {
+    /// Cheapest number of tokens needed to reach the prize, if it can be
+    /// reached at all.
+    pub fn win(&self) -> Option<i64> {
+        let (a_x, a_y) = (self.a.dx, self.a.dy);
+        let (b_x, b_y) = (self.b.dx, self.b.dy);
+        let (p_x, p_y) = self.prize;
+
+        let d1 = a_x*p_y - a_y*p_x;
+        let d2 = a_x*b_y - a_y*b_x;
+
+        // Check if there's an even number of B presses that results in the prize.
+        if d1 % d2 != 0 {
+            return None;
+        }
+
+        let j = (a_x*p_y - a_y*p_x)/(a_x*b_y - a_y*b_x);
+        let d3 = p_x - b_x*j;
+
+        // Check if there's an even number of A presses that results in the prize.
+        if d3 % a_x != 0 {
+            return None;
+        }
+
+        let i = d3/a_x;
+
+        Some(i*self.a.cost + j*self.b.cost)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "
+        Button A: X+94, Y+34
+        Button B: X+22, Y+67
+        Prize: X=8400, Y=5400
+
+        Button A: X+26, Y+66
+        Button B: X+67, Y+21
+        Prize: X=12748, Y=12176
+
+        Button A: X+17, Y+86
+        Button B: X+84, Y+37
+        Prize: X=7870, Y=6450
+
+        Button A: X+69, Y+23
+        Button B: X+27, Y+71
+        Prize: X=18641, Y=10279
+    ";
+
+    #[test]
+    fn test_part1() {
+        let input = Day13::parse(INPUT).unwrap();
+
+        assert_eq!(Day13::part1(&input), 480);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = Day13::parse(INPUT).unwrap();
+
+        assert_eq!(Day13::part2(&input), 875318608908);
+    }
+
+    #[test]
+    fn test_part2_machines() {
+        let mut machines = Day13::parse(INPUT).unwrap();
+        for m in machines.iter_mut() {
+            m.prize.0 += 10_000_000_000_000;
+            m.prize.1 += 10_000_000_000_000;
+        }
+
+        // The puzzle says that only the second and fourth machines can be
+        // won now. The second takes 118679050709 presses of A and
+        // 103199174542 of B, the fourth 102851800151 of A and 107526881786
+        // of B, which adds up to the 875318608908 tokens above.
+        assert_eq!(machines[0].win(), None);
+        assert_eq!(machines[1].win(), Some(3*118679050709 + 103199174542));
+        assert_eq!(machines[2].win(), None);
+        assert_eq!(machines[3].win(), Some(3*102851800151 + 107526881786));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day13::parse("Button A: X+94, Y+34\nButton B: X+22, Y+67").unwrap_err();
+
+        assert_eq!((err.line, err.column), (2, 21));
+        assert_eq!(err.expected, "a 'Prize' line");
+    }
+}
@@ -0,0 +1,256 @@
+use std::{collections::HashSet, fmt::Debug};
+
+use util::{
+    anim::{Animation, Frame, Sink},
+    image::{Palette, Render, Rgb},
+    Grid, ParseError, Solution, Source,
+};
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Problem;
+    type Answer1 = i32;
+    type Answer2 = usize;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        Problem::parse(data)
+    }
+
+    fn part1(problem: &Self::Input) -> i32 {
+        let mut problem = problem.clone();
+
+        problem.run(100);
+
+        problem.solve()
+    }
+
+    fn part2(problem: &Self::Input) -> usize {
+        let mut problem = problem.clone();
+
+        problem.find_tree().expect("no christmas tree found")
+    }
+}
+
+/// Both parts animate the robots, second by second, until they are back
+/// where they started.
+impl Animation for Day14 {
+    fn animate(problem: &Problem, _part: u8, sink: &mut dyn Sink) {
+        let mut problem = problem.clone();
+        let period = problem.width * problem.height;
+
+        for second in 0..period {
+            if !sink.push(Frame::of(&problem, format!("{} seconds", second))) {
+                break;
+            }
+            problem.run(1);
+        }
+    }
+}
+
+/// The robots patrolling the bathroom area.
+#[derive(Clone)]
+pub struct Problem {
+    width: i32,
+    height: i32,
+    robots: Vec<Robot>,
+}
+
+impl Problem {
+    pub fn parse(data: &str) -> Result<Self, ParseError> {
+        let source = Source::new(data);
+        let robots = source.parse_lines(|s| Robot::parse(&source, s))?;
+
+        Ok(Problem { width: 101, height: 103, robots })
+    }
+
+    /// Moves every robot `iterations` seconds ahead.
+    pub fn run(&mut self, iterations: usize) {
+        for _ in 0..iterations {
+            for r in self.robots.iter_mut() {
+                r.travel(self.width, self.height);
+            }
+        }
+    }
+
+    /// Moves the robots to the first frame where no two of them share a
+    /// position, which is where the christmas tree shows up, and returns
+    /// its number. `None` if the robots are back where they started first.
+    pub fn find_tree(&mut self) -> Option<usize> {
+        let mut history = HashSet::new();
+
+        let mut i = 0;
+        while history.insert(self.robots.clone()) {
+            let positions: HashSet<_> = self.robots.iter().map(|r| r.p).collect();
+            if positions.len() == self.robots.len() {
+                return Some(i);
+            }
+            i += 1;
+
+            for r in self.robots.iter_mut() {
+                r.travel(self.width, self.height);
+            }
+        }
+
+        None
+    }
+
+    /// The safety factor: the product of the robot counts in each quadrant.
+    pub fn solve(&mut self) -> i32 {
+        let mut quadrants = (0, 0, 0, 0);
+        for r in self.robots.iter() {
+            let (x, y) = r.p;
+
+            if x < self.width/2 && y < self.height/2 {
+                quadrants.0 += 1;
+            } else if x > self.width/2 && y < self.height/2 {
+                quadrants.1 += 1;
+            } else if x > self.width/2 && y > self.height/2 {
+                quadrants.2 += 1;
+            } else if x < self.width/2 && y > self.height/2 {
+                quadrants.3 += 1;
+            }
+        }
+
+        quadrants.0 * quadrants.1 * quadrants.2 * quadrants.3
+    }
+}
+
+impl Render for Problem {
+    fn render(&self) -> Grid<char> {
+        let mut grid = Grid::new(self.width as usize, self.height as usize, '.');
+
+        for r in self.robots.iter() {
+            if let Some(cell) = grid.get_mut((r.p.0 as usize, r.p.1 as usize)) {
+                *cell = 'X';
+            }
+        }
+
+        grid
+    }
+
+    fn palette(&self) -> Palette {
+        Palette::new(Rgb::BLACK).with('X', Rgb::GREEN)
+    }
+}
+
+impl Debug for Problem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.render())
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+struct Robot {
+    p: (i32, i32),
+    v: (i32, i32),
+}
+
+impl Robot {
+    fn parse<'a>(source: &Source<'a>, s: &'a str) -> Result<Self, ParseError> {
+        let [p, v] = source.record(s, " ")?;
+
+        let (p_x, p_y) = source.split_once(source.strip_prefix(p, "p=")?, ",")?;
+        let (v_x, v_y) = source.split_once(source.strip_prefix(v, "v=")?, ",")?;
+
+        Ok(Robot {
+            p: (source.parse(p_x, "a position")?, source.parse(p_y, "a position")?),
+            v: (source.parse(v_x, "a velocity")?, source.parse(v_y, "a velocity")?),
+        })
+    }
+
+    fn travel(&mut self, width: i32, height: i32) {
+        let (x, y) = self.p;
+        let (v_x, v_y) = self.v;
+
+        let (mut x, mut y) = ((x + v_x), (y + v_y));
+        if x < 0 {
+            x += width;
+        } else if x >= width {
+            x -= width;
+        }
+        if y < 0 {
+            y += height;
+        } else if y >= height {
+            y -= height;
+        }
+
+        self.p = (x, y);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "
+        p=0,4 v=3,-3
+        p=6,3 v=-1,-3
+        p=10,3 v=-1,2
+        p=2,0 v=2,-1
+        p=0,0 v=1,3
+        p=3,0 v=-2,-2
+        p=7,6 v=-1,-3
+        p=3,0 v=-1,-2
+        p=9,3 v=2,3
+        p=7,3 v=-1,2
+        p=2,4 v=2,-3
+        p=9,5 v=-3,-3
+    ";
+
+    #[test]
+    fn test_part1() {
+        let mut problem = Problem::parse(INPUT).unwrap();
+        problem.width = 11;
+        problem.height = 7;
+
+        problem.run(100);
+
+        assert_eq!(problem.solve(), 12);
+    }
+
+    #[test]
+    fn test_travel() {
+        let mut r = Robot{
+            p: (2, 4),
+            v: (2, -3),
+        };
+        let (width, height) = (11, 7);
+
+        for _ in 0..5 {
+            r.travel(width, height);
+            println!("{:?}", Problem{ robots: vec![r], width, height});
+        }
+    }
+
+    #[test]
+    fn test_part2() {
+        let mut problem = Problem::parse(&util::read_input("input.txt").unwrap()).unwrap();
+
+        assert_eq!(problem.find_tree(), Some(6771));
+
+        // The tree is drawn inside a frame 31 robots wide.
+        let frame = "X".repeat(31);
+        assert_eq!(problem.render().to_string().matches(&frame).count(), 2);
+    }
+
+    #[test]
+    fn test_part2_no_tree() {
+        let mut problem = Problem::parse(INPUT).unwrap();
+        problem.width = 11;
+        problem.height = 7;
+
+        // Every frame of the example has robots on top of each other.
+        problem.robots.push(Robot { p: (0, 0), v: (0, 0) });
+        problem.robots.push(Robot { p: (0, 0), v: (0, 0) });
+
+        assert_eq!(problem.find_tree(), None);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Problem::parse("p=0,4 v=3,-3\np=6,3 w=-1,-3").unwrap_err();
+
+        assert_eq!(err.to_string(), "2:7: expected 'v=', found 'w=-1,-3'");
+    }
+}
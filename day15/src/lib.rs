@@ -0,0 +1,700 @@
+use std::{collections::HashSet, fmt::Debug};
+
+use util::{
+    anim::{Animation, Frame, Sink},
+    image::{Palette, Render, Rgb},
+    Grid, ParseError, Pos, Solution, Source,
+};
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Warehouse;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        let source = Source::new(data);
+
+        let (map, instr) = source.two_sections("moves")?;
+
+        let mut robots = 0;
+        let cells = util::lines(map)
+            .map(|s| {
+                s.char_indices()
+                    .map(|(i, c)| match c {
+                        '#' | '.' | 'O' | '[' | ']' => Ok(c),
+                        '@' => {
+                            robots += 1;
+                            Ok(c)
+                        }
+                        _ => Err(source.char_error(s, i, "'#', '.', 'O', '[', ']' or '@'")),
+                    })
+                    .collect()
+            })
+            .collect::<Result<_, _>>()?;
+        if robots != 1 {
+            return Err(source.error(map, "a map with exactly one robot ('@')"));
+        }
+
+        let instructions = instr
+            .char_indices()
+            .filter(|(_, c)| !c.is_whitespace())
+            .map(|(i, c)| {
+                match c {
+                    '^' => Ok((0, -1)),
+                    '>' => Ok((1, 0)),
+                    'v' => Ok((0, 1)),
+                    '<' => Ok((-1, 0)),
+                    _ => Err(source.char_error(instr, i, "one of '^', '>', 'v' or '<'")),
+                }
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Warehouse { cells, instructions })
+    }
+
+    fn part1(warehouse: &Self::Input) -> i32 {
+        let mut map = Map::new(warehouse);
+
+        map.solve()
+    }
+
+    fn part2(warehouse: &Self::Input) -> i32 {
+        let mut map = WideMap::new(warehouse);
+
+        map.solve()
+    }
+}
+
+/// Part 1 animates the robot in the original warehouse, part 2 in the wide
+/// one.
+impl Animation for Day15 {
+    fn animate(warehouse: &Warehouse, part: u8, sink: &mut dyn Sink) {
+        let moves = &warehouse.instructions;
+        if part == 1 {
+            play_moves(&mut Map::new(warehouse), moves, Map::move_robot, sink);
+        } else {
+            play_moves(&mut WideMap::new(warehouse), moves, WideMap::move_robot, sink);
+        }
+    }
+}
+
+/// Pushes a frame of `map` before the first of the robot's moves and after
+/// each of them.
+fn play_moves<M: Render>(
+    map: &mut M,
+    moves: &[Point],
+    move_robot: fn(&mut M, Point),
+    sink: &mut dyn Sink,
+) {
+    if !sink.push(Frame::of(map, "start")) {
+        return;
+    }
+
+    for (i, &d) in moves.iter().enumerate() {
+        move_robot(map, d);
+
+        let arrow = match d {
+            (0, -1) => '^',
+            (1, 0) => '>',
+            (0, 1) => 'v',
+            _ => '<',
+        };
+        if !sink.push(Frame::of(map, format!("move {}/{}: {}", i + 1, moves.len(), arrow))) {
+            return;
+        }
+    }
+}
+
+/// The warehouse layout and the robot's list of moves.
+pub struct Warehouse {
+    cells: Vec<Vec<char>>,
+    instructions: Vec<Point>,
+}
+
+/// The warehouse with boxes that take up a single tile.
+pub struct Map {
+    size: Point,
+    boxes: HashSet<Point>,
+    walls: HashSet<Point>,
+    robot: Point,
+    instructions: Vec<Point>,
+}
+
+impl Map {
+    /// Performs all of the robot's moves and returns the sum of the boxes'
+    /// GPS coordinates.
+    pub fn solve(&mut self) -> i32 {
+        let instructions = self.instructions.clone();
+        for d in instructions.iter() {
+            self.move_robot(*d);
+        }
+
+        let mut result = 0;
+        for (x, y) in self.boxes.iter() {
+            result += x + y*100;
+        }
+        result
+    }
+
+    /// Moves the robot one step, pushing any boxes in the way.
+    pub fn move_robot(&mut self, (dx, dy): Point) {
+        let (x, y) = self.robot;
+        let next = (x+dx, y+dy);
+
+        if let Some(&b) = self.boxes.get(&next) {
+            if self.push_box(b, (dx, dy)) {
+                self.robot = next;
+            }
+        } else if !self.walls.contains(&next) {
+            self.robot = next;
+        }
+    }
+
+    fn push_box(&mut self, (x, y): Point, (dx, dy): Point) -> bool {
+        let mut to_push = Vec::new();
+
+        if let Some(&b) = self.boxes.get(&(x, y)) {
+            let mut colliding_boxes = Vec::new();
+            colliding_boxes.push(b);
+
+            while let Some(b) = colliding_boxes.pop() {
+                let (x, y) = b;
+                
+                to_push.push(b);
+
+                let next = (x+dx, y+dy);
+
+                if self.walls.contains(&next) {
+                    return false;
+                } else if let Some(&b) = self.boxes.get(&next) {
+                    colliding_boxes.push(b);
+                }
+            }
+        }
+
+        for b in to_push.iter() {
+            self.boxes.remove(b);
+        }
+        for &(x, y) in to_push.iter() {
+            self.boxes.insert((x+dx, y+dy));
+        }
+        
+        true
+    }
+
+    pub fn new(warehouse: &Warehouse) -> Self {
+        let cells = &warehouse.cells;
+
+        let mut robot = None;
+        let mut boxes = HashSet::new();
+        let mut walls = HashSet::new();
+
+        for (y, row) in cells.iter().enumerate() {
+            for (x, &c) in row.iter().enumerate() {
+                match c {
+                    '#' => {
+                        let pos = (x as i32, y as i32);
+                        walls.insert(pos);
+                    }
+                    'O' => {
+                        let pos = (x as i32, y as i32);
+                        boxes.insert(pos);
+                    }
+                    '@' => {
+                        robot = Some((x as i32, y as i32));
+                    }
+                    _ => {}
+                }
+                
+            }
+        }
+
+        Map {
+            size: (cells[0].len() as i32, cells.len() as i32),
+            robot: robot.unwrap(),
+            boxes,
+            walls,
+            instructions: warehouse.instructions.clone(),
+        }
+    }
+}
+
+impl Render for Map {
+    fn render(&self) -> Grid<char> {
+        let (width, height) = self.size;
+        let mut grid = Grid::new(width as usize, height as usize, '.');
+
+        for (pos, cell) in grid.iter_mut() {
+            let pos = (pos.0 as i32, pos.1 as i32);
+            if self.walls.contains(&pos) {
+                *cell = '#';
+            } else if self.boxes.contains(&pos) {
+                *cell = 'O';
+            } else if pos == self.robot {
+                *cell = '@';
+            }
+        }
+
+        grid
+    }
+
+    fn palette(&self) -> Palette {
+        palette()
+    }
+
+    fn highlight(&self) -> Vec<Pos> {
+        vec![(self.robot.0 as usize, self.robot.1 as usize)]
+    }
+}
+
+impl Debug for Map {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.render())
+    }
+}
+
+/// The warehouse scaled up to twice the width, with boxes two tiles wide.
+pub struct WideMap {
+    size: Point,
+    boxes: HashSet<Point>,
+    walls: HashSet<Point>,
+    robot: Point,
+    instructions: Vec<Point>,
+}
+
+impl WideMap {
+    /// Performs all of the robot's moves and returns the sum of the boxes'
+    /// GPS coordinates, measured from their left edge.
+    pub fn solve(&mut self) -> i32 {
+        let instructions = self.instructions.clone();
+        for d in instructions.iter() {
+            self.move_robot(*d);
+        }
+
+        let mut result = 0;
+        for (x, y) in self.boxes.iter() {
+            result += x + y*100;
+        }
+        result
+    }
+
+    /// Moves the robot one step, pushing any boxes in the way.
+    pub fn move_robot(&mut self, (dx, dy): Point) {
+        let (x, y) = self.robot;
+        let next = (x+dx, y+dy);
+
+        if let Some(&b) = self.get_box(next) {
+            if self.push_box(b, (dx, dy)) {
+                self.robot = next;
+            }
+        } else if self.get_wall(next).is_none() {
+            self.robot = next;
+        }
+    }
+
+    fn push_box(&mut self, (x, y): Point, (dx, dy): Point) -> bool {
+        let mut to_push = Vec::new();
+
+        if let Some(&b) = self.get_box((x, y)) {
+            let mut colliding_boxes = Vec::new();
+            colliding_boxes.push(b);
+
+            while let Some(b) = colliding_boxes.pop() {
+                let (x, y) = b;
+                
+                to_push.push(b);
+
+                if dx > 0 {
+                    let next = (x+2, y);
+                    if self.get_wall(next).is_some() {
+                        return false;
+                    }
+                    if let Some(&b) = self.get_box(next) {
+                        colliding_boxes.push(b);
+                    }
+                } else if dx < 0 {
+                    let next = (x-1, y);
+                    if self.get_wall(next).is_some() {
+                        return false;
+                    }
+                    if let Some(&b) = self.get_box(next) {
+                        colliding_boxes.push(b);
+                    }
+                } else {
+                    let first = (x, y+dy);
+                    let second = (x+1, y+dy);
+
+                    if self.get_wall(first).is_some() || self.get_wall(second).is_some() {
+                        return false;
+                    }
+
+                    match (self.get_box(first), self.get_box(second)) {
+                        (Some(&b1), Some(&b2)) if b1 != b2 => {
+                            colliding_boxes.push(b1);
+                            colliding_boxes.push(b2);
+                        },
+                        (Some(&b), _) | (_, Some(&b)) => {
+                            colliding_boxes.push(b);
+                        },
+                        _ => {},
+                    }
+                }
+            }
+        }
+
+        for b in to_push.iter() {
+            self.boxes.remove(b);
+        }
+        for &(x, y) in to_push.iter() {
+            self.boxes.insert((x+dx, y+dy));
+        }
+        
+        true
+    }
+
+    fn get_box(&self, (x, y): Point) -> Option<&Point> {
+        self.boxes.get(&(x, y))
+            .or(self.boxes.get(&(x-1,y)))
+    }
+
+    fn get_wall(&self, (x, y): Point) -> Option<&Point> {
+        self.walls.get(&(x, y))
+            .or(self.walls.get(&(x-1, y)))
+    }
+
+    pub fn new(warehouse: &Warehouse) -> Self {
+        let cells = &warehouse.cells;
+
+        let mut robot = None;
+        let mut boxes = HashSet::new();
+        let mut walls = HashSet::new();
+
+        // Maps that already contain wide boxes are used as they are.
+        let scale = if cells.iter().flatten().any(|&c| c == '[') { 1 } else { 2 };
+
+        for (y, row) in cells.iter().enumerate() {
+            for (x, &c) in row.iter().enumerate() {
+                let pos = ((x as i32)*scale, y as i32);
+                match c {
+                    '#' if pos.0 % 2 == 0 => {
+                        walls.insert(pos);
+                    }
+                    'O' | '[' => {
+                        boxes.insert(pos);
+                    }
+                    '@' => {
+                        robot = Some(pos);
+                    }
+                    _ => {}
+                }
+                
+            }
+        }
+
+        WideMap {
+            size: ((cells[0].len() as i32)*scale, cells.len() as i32),
+            robot: robot.unwrap(),
+            boxes,
+            walls,
+            instructions: warehouse.instructions.clone(),
+        }
+    }
+}
+
+impl Render for WideMap {
+    fn render(&self) -> Grid<char> {
+        let (width, height) = self.size;
+        let mut grid = Grid::new(width as usize, height as usize, '.');
+
+        for (pos, cell) in grid.iter_mut() {
+            let pos = (pos.0 as i32, pos.1 as i32);
+            if self.get_wall(pos).is_some() {
+                *cell = '#';
+            } else if let Some(&(b_x, _)) = self.get_box(pos) {
+                *cell = if b_x == pos.0 { '[' } else { ']' };
+            } else if pos == self.robot {
+                *cell = '@';
+            }
+        }
+
+        grid
+    }
+
+    fn palette(&self) -> Palette {
+        palette()
+    }
+
+    fn highlight(&self) -> Vec<Pos> {
+        vec![(self.robot.0 as usize, self.robot.1 as usize)]
+    }
+}
+
+impl Debug for WideMap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.render())
+    }
+}
+
+fn palette() -> Palette {
+    Palette::new(Rgb::BLACK)
+        .with('#', Rgb::GRAY)
+        .with('O', Rgb::BROWN)
+        .with('[', Rgb::BROWN)
+        .with(']', Rgb::BROWN)
+        .with('@', Rgb::RED)
+}
+
+type Point = (i32, i32);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "
+        ##########
+        #..O..O.O#
+        #......O.#
+        #.OO..O.O#
+        #..O@..O.#
+        #O#..O...#
+        #O..O..O.#
+        #.OO.O.OO#
+        #....O...#
+        ##########
+
+        <vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
+        vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
+        ><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
+        <<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
+        ^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
+        ^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
+        >^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
+        <><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
+        ^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
+        v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^
+    ";
+
+    #[test]
+    fn test_part1() {
+        let input = Day15::parse(INPUT).unwrap();
+
+        assert_eq!(Day15::part1(&input), 10092);
+    }
+
+    #[test]
+    fn test_part1_ex1() {
+        const INPUT: &str = "
+            ########
+            #..O.O.#
+            ##@.O..#
+            #...O..#
+            #.#.O..#
+            #...O..#
+            #......#
+            ########
+
+            <^^>>>vv<v>>v<<
+        ";
+        let input = Day15::parse(INPUT).unwrap();
+
+        assert_eq!(Day15::part1(&input), 2028);
+
+        let mut frames = Vec::new();
+        Day15::animate(&input, 1, &mut frames);
+        assert_eq!(frames.len(), 16);
+        assert_eq!(frames[15].caption, "move 15/15: <");
+        assert_eq!(frames[15].highlight, vec![(4, 4)]);
+        assert_eq!(
+            frames[15].grid.to_string(),
+            "########\n#....OO#\n##.....#\n#.....O#\n#.#O@..#\n#...O..#\n#...O..#\n########\n"
+        );
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = Day15::parse(INPUT).unwrap();
+
+        assert_eq!(Day15::part2(&input), 9021);
+    }
+
+    #[test]
+    fn test_part2_ex1() {
+        const INPUT: &str = "
+            #######
+            #...#.#
+            #.....#
+            #..OO@#
+            #..O..#
+            #.....#
+            #######
+
+            <vv<<^^<<^^
+        ";
+        let input = Day15::parse(INPUT).unwrap();
+
+        assert_eq!(Day15::part2(&input), 618);
+    }
+
+    #[test]
+    fn test_part2_ex2() {
+        const INPUT: &str = "
+            #######
+            #.....#
+            #.....#
+            #.@O..#
+            #..#O.#
+            #...O.#
+            #..O..#
+            #.....#
+            #######
+
+            >><vvv>v>^^^
+        ";
+
+        let input = Day15::parse(INPUT).unwrap();
+
+        assert_eq!(Day15::part2(&input), 1430);
+    }
+
+    #[test]
+    fn test_part2_ex4() {
+        const INPUT: &str = "
+        #######
+        #.....#
+        #..O..#
+        #@OO..#
+        #..O..#
+        #..O..#
+        #.....#
+        #######
+
+        >>^^>>v
+        ";
+
+        let input = Day15::parse(INPUT).unwrap();
+
+        assert_eq!(Day15::part2(&input), 2230);
+    }
+
+    #[test]
+    fn test_part2_ex5() {
+        const INPUT: &str = "
+        ############
+        #OO.O....#.#
+        #O.OO.O....#
+        #..O.#O..O##
+        #OOO.......#
+        #...O@O#O.O#
+        #......O...#
+        ##....O.O..#
+        #.....OO...#
+        #..#.O..O.##
+        #O.O.#...O.#
+        ############
+
+        ^<><>^<^v>^>vv^>><^>>v^v^vv>^v^>>^^<^^^^vv<<^>v^><<<<v>^>v>^^<^^vv^^v<
+        <<^^v^<<>v>v<^<vv^vv>v^>v^<<v<vv>v>v<>^v><v<<>v<^<vvvv><>>>v>^v>^<>v^^
+        ^>v>>^<^<><v>>><>>vv<<<v^v>v^v^^vv>v>v>vvv>^^<^^vv>^v<>v^^^>^v^><^vv^>
+        ^<>v>>^>vv<v^v<^^vv<^><vv^<^v^^vv><<^^^^<<^v>v>>^v^^>>v^v<v<><^v^>v<<<
+        >>^<^v<><^<v>>>v^<<v>^>v><<vvv<<^<v>^<vvv>v>vv^<^^>>^^vv^^v<^<^>v^<>>^
+        <^^<>>v^<><<^>v<^>^<v<>>^<<>^<>vv<>v>vv>>>^<<v^^^<<^vv<<vv^^^^^v>v>><<
+        vv<^vv^^^<vv<^vv<^vv<<<><v>><vv^<>^v^>><v^>><<^^>vv><>v<<<v>v^vv>>>^^>
+        ^<^v^v<<^><vv^vv>v^>v^<<v<vv>>^<>vv<>v>vv>>>^<<v^^v<>^v><v<<>v<^<vvvv>
+        ";
+
+        let input = Day15::parse(INPUT).unwrap();
+
+        assert_eq!(Day15::part2(&input), 13570);
+    }
+
+    #[test]
+    fn test_part2_ex6() {
+        const INPUT: &str = "
+        ######
+        #.####
+        #.OO@#
+        #.O..#
+        #....#
+        ######
+
+        <<vv<<^
+        ";
+
+        let input = Day15::parse(INPUT).unwrap();
+
+        // Widened, the robot pushes both boxes on its row two tiles left,
+        // then walks around under the lower box and can't push it up into
+        // the boxes above it, which are against the wall:
+        //
+        //   ############
+        //   ##..########
+        //   ##[][]....##
+        //   ##..[]....##
+        //   ##..@.....##
+        //   ############
+        //
+        // The GPS coordinates are 202 + 204 + 304.
+        assert_eq!(Day15::part2(&input), 710);
+    }
+
+    #[test]
+    fn test_part2_ex7() {
+        const INPUT: &str = "
+        ########
+        ##..####
+        ##[]..##
+        ##.[].##
+        ##..[]##
+        ##..@.##
+        ########
+
+        ^
+        ";
+
+        let input = Day15::parse(INPUT).unwrap();
+
+        // A map that is already wide is used as it is. Each box rests on
+        // half of the one above it, so pushing up moves all three:
+        //
+        //   ########
+        //   ##[]####
+        //   ##.[].##
+        //   ##..[]##
+        //   ##..@.##
+        //   ########
+        //
+        // The GPS coordinates are 102 + 203 + 304.
+        assert_eq!(Day15::part2(&input), 609);
+    }
+
+    #[test]
+    fn test_part2_ex8() {
+        const INPUT: &str = "
+
+        ########
+        ##....##
+        ##.[].##
+        ####[]##
+        ##..@.##
+        ########
+
+        ^
+        ";
+
+        let input = Day15::parse(INPUT).unwrap();
+
+        // The upper box has nothing above it, so both boxes move:
+        //
+        //   ########
+        //   ##.[].##
+        //   ##..[]##
+        //   ####@.##
+        //   ##....##
+        //   ########
+        //
+        // The GPS coordinates are 103 + 204.
+        assert_eq!(Day15::part2(&input), 307);
+    }
+}
@@ -0,0 +1,273 @@
+use std::{collections::HashSet, fmt::Debug};
+
+use util::{
+    anim::{Animation, Frame, Sink},
+    geom::{Direction, Point},
+    image::{Palette, Render, Rgb},
+    search::{self, Search},
+    Grid, ParseError, Pos, Solution, Source,
+};
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Map;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        Map::parse(data)
+    }
+
+    fn part1(map: &Self::Input) -> usize {
+        map.solve()
+    }
+
+    fn part2(map: &Self::Input) -> usize {
+        map.solve_part2()
+    }
+}
+
+/// The reindeer maze with its start and end tiles.
+pub struct Map {
+    size: (i32, i32),
+    walls: HashSet<Point>,
+    start: Point,
+    end: Point,
+}
+
+/// Where a reindeer is and which way it faces.
+type Reindeer = (Point, Direction);
+
+impl Map {
+    pub fn parse(data: &str) -> Result<Self, ParseError> {
+        let source = Source::new(data);
+        let lines = source.lines().collect::<Vec<_>>();
+
+        let mut walls = HashSet::new();
+        let mut start = None;
+        let mut end = None;
+
+        for (y, line) in lines.iter().enumerate() {
+            for (x, c) in line.char_indices() {
+                let pos = Point::new(x as i32, y as i32);
+                match c {
+                    '#' => { walls.insert(pos); },
+                    'S' => { start = Some(pos); }
+                    'E' => { end = Some(pos); },
+                    '.' => {},
+                    _ => return Err(source.char_error(line, x, "'#', '.', 'S' or 'E'")),
+                }
+            }
+        }
+
+        Ok(Map {
+            size: (lines.first().map_or(0, |l| l.len()) as i32, lines.len() as i32),
+            walls,
+            start: start.ok_or_else(|| source.missing_after(data.trim_end(), "a start tile ('S')"))?,
+            end: end.ok_or_else(|| source.missing_after(data.trim_end(), "an end tile ('E')"))?,
+        })
+    }
+
+    /// Lowest score a reindeer can get walking from the start to the end.
+    pub fn solve(&self) -> usize {
+        self.search().goal_distance().expect("this is impossible!")
+    }
+
+    /// Number of tiles that are part of at least one of the best paths.
+    pub fn solve_part2(&self) -> usize {
+        self.nice_places(&self.search()).len()
+    }
+
+    /// The tiles on any of the best paths found by `search`.
+    fn nice_places(&self, search: &Search<Reindeer>) -> HashSet<Point> {
+        let best = search.goal_distance().expect("this is impossible!");
+
+        // The end may be reached facing different ways for the same score.
+        let winners = Direction::ALL
+            .map(|d| (self.end, d))
+            .into_iter()
+            .filter(|r| search.distance(r) == Some(best));
+
+        search
+            .on_paths(winners)
+            .into_iter()
+            .map(|(pos, _)| pos)
+            .collect()
+    }
+
+    /// Searches from the start, facing east, until the end is reached.
+    /// Stepping forward scores 1 point and turning in place 1000.
+    fn search(&self) -> Search<Reindeer> {
+        let moves = |&(pos, d): &Reindeer| {
+            let mut moves = vec![((pos, d.turn_left()), 1000), ((pos, d.turn_right()), 1000)];
+            if !self.walls.contains(&pos.step(d)) {
+                moves.push(((pos.step(d), d), 1));
+            }
+            moves
+        };
+
+        search::dijkstra((self.start, Direction::East), moves, |&(pos, _)| pos == self.end)
+    }
+}
+
+impl Render for Map {
+    fn render(&self) -> Grid<char> {
+        let (width, height) = self.size;
+        let mut grid = Grid::new(width as usize, height as usize, '.');
+
+        for (pos, cell) in grid.iter_mut() {
+            let pos = Point::from(pos);
+            if self.walls.contains(&pos) {
+                *cell = '#';
+            } else if pos == self.start {
+                *cell = 'S';
+            } else if pos == self.end {
+                *cell = 'E';
+            }
+        }
+
+        grid
+    }
+
+    fn palette(&self) -> Palette {
+        Palette::new(Rgb::BLACK)
+            .with('#', Rgb::GRAY)
+            .with('S', Rgb::GREEN)
+            .with('E', Rgb::RED)
+    }
+}
+
+/// Both parts animate a reindeer walking one of the best paths. Part 2 also
+/// marks every other tile on a best path.
+impl Animation for Day16 {
+    fn animate(map: &Map, part: u8, sink: &mut dyn Sink) {
+        let search = map.search();
+        let Some(path) = search.goal.as_ref().and_then(|goal| search.path(goal)) else {
+            return;
+        };
+
+        let mut walk = Walk {
+            map,
+            nice_places: if part == 2 { map.nice_places(&search) } else { HashSet::new() },
+            trail: HashSet::new(),
+            reindeer: path[0],
+        };
+        for reindeer in path {
+            walk.reindeer = reindeer;
+            walk.trail.insert(reindeer.0);
+
+            if !sink.push(Frame::of(&walk, format!("score {}", search.distances[&reindeer]))) {
+                return;
+            }
+        }
+    }
+}
+
+/// A reindeer part of the way along a path through the maze.
+struct Walk<'a> {
+    map: &'a Map,
+    nice_places: HashSet<Point>,
+    trail: HashSet<Point>,
+    reindeer: Reindeer,
+}
+
+impl Render for Walk<'_> {
+    fn render(&self) -> Grid<char> {
+        let mut grid = self.map.render();
+
+        for (pos, cell) in grid.iter_mut() {
+            let pos = Point::from(pos);
+            if pos == self.reindeer.0 {
+                *cell = self.reindeer.1.arrow();
+            } else if self.trail.contains(&pos) {
+                *cell = 'O';
+            } else if *cell == '.' && self.nice_places.contains(&pos) {
+                *cell = 'o';
+            }
+        }
+
+        grid
+    }
+
+    fn palette(&self) -> Palette {
+        Direction::ALL
+            .into_iter()
+            .fold(self.map.palette(), |p, d| p.with(d.arrow(), Rgb::RED))
+            .with('O', Rgb::YELLOW)
+            .with('o', Rgb::BROWN)
+    }
+
+    fn highlight(&self) -> Vec<Pos> {
+        self.reindeer.0.to_pos().into_iter().collect()
+    }
+}
+
+impl Debug for Map {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.render())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "
+        ###############
+        #.......#....E#
+        #.#.###.#.###.#
+        #.....#.#...#.#
+        #.###.#####.#.#
+        #.#.#.......#.#
+        #.#.#####.###.#
+        #...........#.#
+        ###.#.#####.#.#
+        #...#.....#.#.#
+        #.#.#.###.#.#.#
+        #.....#...#.#.#
+        #.###.#.#.#.#.#
+        #S..#.....#...#
+        ###############
+    ";
+
+    #[test]
+    fn test_part1() {
+        let input = Day16::parse(INPUT).unwrap();
+
+        assert_eq!(Day16::part1(&input), 7036);
+    }
+
+    #[test]
+    fn test_part2_ex1() {
+        let input = Day16::parse(INPUT).unwrap();
+
+        assert_eq!(Day16::part2(&input), 45);
+    }
+
+    #[test]
+    fn test_part2_ex2() {
+        const INPUT: &str = "
+            #################
+            #...#...#...#..E#
+            #.#.#.#.#.#.#.#.#
+            #.#.#.#...#...#.#
+            #.#.#.#.###.#.#.#
+            #...#.#.#.....#.#
+            #.#.#.#.#.#####.#
+            #.#...#.#.#.....#
+            #.#.#####.#.###.#
+            #.#.#.......#...#
+            #.#.###.#####.###
+            #.#.#...#.....#.#
+            #.#.#.#####.###.#
+            #.#.#.........#.#
+            #.#.#.#########.#
+            #S#.............#
+            #################
+        ";
+        let input = Day16::parse(INPUT).unwrap();
+
+        assert_eq!(Day16::part2(&input), 64);
+    }
+}
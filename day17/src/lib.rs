@@ -0,0 +1,374 @@
+use util::{ParseError, Solution, Source};
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Program;
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        Program::parse(data)
+    }
+
+    fn part1(program: &Self::Input) -> String {
+        let mut p = program.clone();
+
+        p.run();
+
+        p.output().iter().map(|v| v.to_string()).collect::<Vec<_>>().join(",")
+    }
+
+    fn part2(program: &Self::Input) -> String {
+        // Without a constant shift, try three bits, as the puzzle's own
+        // programs shift.
+        let shift = program.shift().unwrap_or(3);
+
+        let last = program.raw_program.len().checked_sub(1);
+        match last.and_then(|start| next_match(program, shift, start, 0)) {
+            Some(a) => a.to_string(),
+            None => "this is impossible!".to_string(),
+        }
+    }
+}
+
+/// Most instructions a candidate for A may run before the program is taken
+/// to never halt with it.
+const STEP_LIMIT: usize = 100_000;
+
+/// Finds the lowest A that outputs the program from `start` on, given the
+/// bits of A above the last `shift` ones, `v`. Each output comes from one
+/// more `shift` bits of A, so the program is matched from the back.
+fn next_match(original: &Program, shift: i64, start: usize, v: i64) -> Option<i64> {
+    let to_match = &original.raw_program.clone()[start..];
+
+    for i in 0..1 << shift {
+        let a = (v << shift) | i;
+
+        let mut p = original.clone();
+        p.a = a;
+
+        if p.outputs(to_match, STEP_LIMIT) {
+            if start == 0 {
+                return Some(a);
+            } else if let Some(a) = next_match(original, shift, start - 1, a) {
+                return Some(a);
+            }
+        }
+    }
+
+    None
+}
+
+/// A program for the 3-bit computer, together with its registers and
+/// execution state.
+#[derive(Clone)]
+pub struct Program {
+    a: i64,
+    b: i64,
+    c: i64,
+
+    pc: usize,
+    output: Vec<i64>,
+    instructions: Vec<Instruction>,
+    raw_program: Vec<i64>,
+}
+
+impl Program {
+    pub fn parse(data: &str) -> Result<Self, ParseError> {
+        let source = Source::new(data);
+        let (regs, instr) = source.two_sections("the program")?;
+
+        let mut lines = util::lines(regs);
+        let mut register = |prev: &str, name: &str| {
+            let line = source.next(&mut lines, prev, &format!("register {}", name))?;
+            let value = source.strip_prefix(line, &format!("Register {}: ", name))?;
+            source.parse(value, "a register value").map(|v| (line, v))
+        };
+
+        let (line, a) = register(regs, "A")?;
+        let (line, b) = register(line, "B")?;
+        let (_, c) = register(line, "C")?;
+
+        let program = source.strip_prefix(instr.trim(), "Program: ")?;
+        let raw_program: Vec<i64> = program
+            .split(",")
+            .map(|s| match source.parse(s, "a 3-bit number") {
+                Ok(v) if (0..8).contains(&v) => Ok(v),
+                _ => Err(source.error(s, "a 3-bit number")),
+            })
+            .collect::<Result<_, _>>()?;
+        if !raw_program.len().is_multiple_of(2) {
+            return Err(source.missing_after(program, "an operand"));
+        }
+
+        let instructions: Vec<Instruction> = raw_program
+            .chunks(2)
+            .map(|chunk| Instruction::from((chunk[0], chunk[1])))
+            .collect();
+
+        Ok(Program {
+            a,
+            b,
+            c,
+            pc: 0,
+            instructions,
+            output: Vec::new(),
+            raw_program,
+        })
+    }
+
+    /// Runs the program until it halts.
+    pub fn run(&mut self) {
+        while self.step() {}
+    }
+
+    /// Runs the program until it halts, and returns whether it output
+    /// exactly `expected`. Gives up as soon as the output differs from it,
+    /// or after `limit` instructions.
+    pub fn outputs(&mut self, expected: &[i64], limit: usize) -> bool {
+        for _ in 0..limit {
+            if !expected.starts_with(&self.output) {
+                return false;
+            }
+            if !self.step() {
+                return self.output == expected;
+            }
+        }
+
+        false
+    }
+
+    /// Executes a single instruction. Returns `false` once the program has
+    /// halted.
+    pub fn step(&mut self) -> bool {
+        if let Some(instr) = self.instructions.get(self.pc) {
+            match instr {
+                Instruction::Adv { combo } => {
+                    let num = self.a;
+                    let denom = 1 << self.combo_value(*combo);
+    
+                    self.a = num/denom;
+                    self.pc += 1;
+                },
+                Instruction::Bdv { combo } => {
+                    let num = self.a;
+                    let denom = 1 << self.combo_value(*combo);
+    
+                    self.b = num/denom;
+                    self.pc += 1;
+                },
+                Instruction::Cdv { combo } => {
+                    let num = self.a;
+                    let denom = 1 << self.combo_value(*combo);
+    
+                    self.c = num/denom;
+                    self.pc += 1;
+                },
+                Instruction::Bxl { literal } => {
+                    self.b ^= literal;
+                    self.pc += 1;
+                },
+                Instruction::Bxc { v: _ } => {
+                    self.b ^= self.c;
+                    self.pc += 1;
+                },
+                Instruction::Bst { combo } => {
+                    self.b = self.combo_value(*combo) % 8;
+                    self.pc += 1;
+                },
+                Instruction::Jnz { literal } => {
+                    if self.a != 0 {
+                        self.pc = *literal as usize;
+                    } else {
+                        self.pc += 1;
+                    }
+                },
+                Instruction::Out { combo } => {
+                    self.output.push(self.combo_value(*combo) % 8);
+                    self.pc += 1;
+                },
+            }
+
+            true
+        } else {
+            false
+        }
+        
+    }
+
+    /// How many bits the program shifts A right by on each loop, if it
+    /// does so by a constant.
+    pub fn shift(&self) -> Option<i64> {
+        self.instructions.iter().find_map(|instr| match instr {
+            Instruction::Adv { combo } if (1..=3).contains(combo) => Some(*combo),
+            _ => None,
+        })
+    }
+
+    /// Values written by `out` instructions so far.
+    pub fn output(&self) -> &[i64] {
+        &self.output
+    }
+
+    fn combo_value(&self, combo: i64) -> i64 {
+        match combo {
+            _ if combo <= 3 => combo,
+            4 => self.a,
+            5 => self.b,
+            6 => self.c,
+            _ => panic!("invalid combo value: {}", combo),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Instruction {
+    Adv { combo: i64 },
+    Bdv { combo: i64 },
+    Cdv { combo: i64 },
+    Bxl { literal: i64 },
+    Bxc { v: i64 },
+    Bst { combo: i64 },
+    Jnz { literal: i64 },
+    Out { combo: i64 },
+}
+
+impl Instruction {
+    fn from((op, v): (i64, i64)) -> Instruction {
+        match op {
+            0 => Self::Adv { combo: v },
+            1 => Self::Bxl { literal: v },
+            2 => Self::Bst { combo: v },
+            3 => Self::Jnz { literal: v },
+            4 => Self::Bxc { v },
+            5 => Self::Out { combo: v },
+            6 => Self::Bdv { combo: v },
+            7 => Self::Cdv { combo: v },
+            _ => panic!("invalid opcode: {}", op),
+        }
+    }
+}
+
+impl std::fmt::Display for Instruction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Instruction::Adv { combo } => write!(f, "{},{}", 0, combo),
+            Instruction::Bxl { literal } => write!(f, "{},{}", 1, literal),
+            Instruction::Bst { combo } => write!(f, "{},{}", 2, combo),
+            Instruction::Jnz { literal } => write!(f, "{},{}", 3, literal),
+            Instruction::Bxc { v } => write!(f, "{},{}", 4, v),
+            Instruction::Out { combo } => write!(f, "{},{}", 5, combo),
+            Instruction::Bdv { combo } => write!(f, "{},{}", 6, combo),
+            Instruction::Cdv { combo } => write!(f, "{},{}", 7, combo),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        const INPUT: &str = "
+            Register A: 729
+            Register B: 0
+            Register C: 0
+
+            Program: 0,1,5,4,3,0
+        ";
+        let input = Day17::parse(INPUT).unwrap();
+
+        assert_eq!(Day17::part1(&input), "4,6,3,5,6,3,5,2,1,0");
+    }
+
+    #[test]
+    fn test_part2() {
+        const INPUT: &str = "
+            Register A: 30899381
+            Register B: 0
+            Register C: 0
+
+            Program: 2,4,1,1,7,5,4,0,0,3,1,6,5,5,3,0
+        ";
+        let input = Day17::parse(INPUT).unwrap();
+
+        assert_eq!(Day17::part2(&input), "247839653009594");
+    }
+
+    #[test]
+    fn test_part2_shift() {
+        // Shifts A by two bits per output rather than three.
+        const INPUT: &str = "
+            Register A: 575
+            Register B: 0
+            Register C: 0
+
+            Program: 0,2,2,4,5,5,3,0
+        ";
+        let input = Day17::parse(INPUT).unwrap();
+
+        assert_eq!(Day17::part2(&input), "54432");
+    }
+
+    #[test]
+    fn test_part2_without_shift() {
+        // Shifts A by B, which is always 3.
+        let input = Day17::parse("
+            Register A: 1
+            Register B: 0
+            Register C: 0
+
+            Program: 2,3,0,5,5,4,3,0
+        ").unwrap();
+
+        assert_eq!(input.shift(), None);
+        assert_eq!(Day17::part2(&input), 0o34550320.to_string());
+    }
+
+    #[test]
+    fn test_part2_never_halts() {
+        // Jumps back to the start for as long as A isn't 0, without ever
+        // changing it or outputting anything.
+        let input = Day17::parse("
+            Register A: 1
+            Register B: 0
+            Register C: 0
+
+            Program: 0,0,3,0
+        ").unwrap();
+
+        assert_eq!(Day17::part2(&input), "this is impossible!");
+    }
+
+    #[test]
+    fn test_part2_empty() {
+        let mut input = Day17::parse("
+            Register A: 1
+            Register B: 0
+            Register C: 0
+
+            Program: 3,0
+        ").unwrap();
+        input.raw_program.clear();
+        input.instructions.clear();
+
+        assert_eq!(Day17::part2(&input), "this is impossible!");
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = "
+            Register A: 729
+            Register B: 0
+            Register C: 0
+
+            Program: 0,1,5,8,3,0
+        ";
+        let err = Program::parse(input).err().unwrap();
+
+        assert_eq!((err.line, err.column), (6, 28));
+        assert_eq!(err.text, "8");
+    }
+}
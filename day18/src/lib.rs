@@ -0,0 +1,150 @@
+use std::collections::HashSet;
+
+use util::{search, ParseError, Solution, Source};
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Problem;
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        Problem::parse(data)
+    }
+
+    fn part1(problem: &Self::Input) -> String {
+        match problem.solve(71, 1024) {
+            Some(steps) => steps.to_string(),
+            None => "this is impossible!".to_string(),
+        }
+    }
+
+    fn part2(problem: &Self::Input) -> String {
+        for rounds in 1..=problem.bytes.len() {
+            let result = problem.solve(71, rounds);
+            let (x, y) = problem.bytes[rounds-1];
+            if result.is_none() {
+                return format!("{},{}", x, y);
+            }
+        }
+
+        "this is impossible!".to_string()
+    }
+}
+
+/// The positions of the falling bytes, in the order they fall.
+pub struct Problem {
+    bytes: Vec<(i32, i32)>,
+}
+
+impl Problem {
+    pub fn parse(data: &str) -> Result<Self, ParseError> {
+        let source = Source::new(data);
+        let bytes = source.parse_lines(|l| {
+            let [x, y] = source.record(l, ",")?;
+
+            Ok((source.parse(x, "a coordinate")?, source.parse(y, "a coordinate")?))
+        })?;
+
+        Ok(Problem {
+            bytes,
+        })
+    }
+
+    /// Length of the shortest path across a `size` by `size` memory space
+    /// after the first `rounds` bytes have fallen, if the exit is reachable.
+    pub fn solve(&self, size: i32, rounds: usize) -> Option<usize> {
+        let corrupted: HashSet<_> = self.bytes.iter().take(rounds).copied().collect();
+
+        let successors = |&(x, y): &(i32, i32)| {
+            [(x, y + 1), (x - 1, y), (x + 1, y), (x, y - 1)]
+                .into_iter()
+                .filter(|&(x, y)| x >= 0 && x < size && y >= 0 && y < size)
+                .filter(|pos| !corrupted.contains(pos))
+                .collect::<Vec<_>>()
+        };
+
+        search::bfs((0, 0), successors, |&pos| pos == (size - 1, size - 1)).goal_distance()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "
+        5,4
+        4,2
+        4,5
+        3,0
+        2,1
+        6,3
+        2,4
+        1,5
+        0,6
+        3,3
+        2,6
+        5,1
+        1,2
+        5,5
+        2,5
+        6,5
+        1,4
+        0,4
+        6,4
+        1,1
+        6,1
+        1,0
+        0,5
+        1,6
+        2,0
+    ";
+
+    #[test]
+    fn test_part1() {
+        let problem = Problem::parse(INPUT).unwrap();
+    
+        assert_eq!(problem.solve(7, 12), Some(22));
+    }
+
+    #[test]
+    fn test_part1_real() {
+        let input = Day18::parse(&util::read_input("input.txt").unwrap()).unwrap();
+
+        assert_eq!(Day18::part1(&input), "324");
+    }
+
+    #[test]
+    fn test_blocked() {
+        // The last of the bytes walls the start in.
+        let input = Day18::parse("1,0\n0,1\n").unwrap();
+
+        assert_eq!(Day18::part1(&input), "this is impossible!");
+        assert_eq!(Day18::part2(&input), "0,1");
+
+        let input = Day18::parse("5,5\n").unwrap();
+
+        assert_eq!(Day18::part1(&input), "140");
+        assert_eq!(Day18::part2(&input), "this is impossible!");
+    }
+
+    #[test]
+    fn test_part2() {
+        let problem = Problem::parse(INPUT).unwrap();
+    
+        let mut result = None;
+        for rounds in 1..problem.bytes.len() {
+            if problem.solve(7, rounds).is_none() {
+                result = Some(problem.bytes[rounds-1]);
+                break;
+            }
+        };
+
+        if let Some(pos) = result {
+            assert_eq!(pos, (6,1));
+        } else {
+            panic!("this is impossible!");
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use std::collections::{HashMap, HashSet};
+
+use util::{ParseError, Solution, Source};
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = Problem;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        Problem::parse(data)
+    }
+
+    fn part1(problem: &Self::Input) -> usize {
+        problem.solve_part1()
+    }
+
+    fn part2(problem: &Self::Input) -> usize {
+        problem.solve_part2()
+    }
+}
+
+/// The available towel patterns and the designs to make from them.
+pub struct Problem {
+    patterns: HashSet<String>,
+    designs: Vec<String>,
+}
+
+impl Problem {
+    pub fn parse(data: &str) -> Result<Self, ParseError> {
+        let source = Source::new(data);
+        let (patterns, designs) = source.two_sections("designs")?;
+
+        let stripes = |s: &str| match s.find(|c| !"wubrg".contains(c)) {
+            Some(i) => Err(source.char_error(s, i, "a stripe color (w, u, b, r or g)")),
+            None if s.is_empty() => Err(source.error(s, "a towel pattern")),
+            None => Ok(s.to_string()),
+        };
+
+        let patterns = patterns.trim().split(", ").map(stripes).collect::<Result<_, _>>()?;
+        let designs = util::lines(designs).map(stripes).collect::<Result<_, _>>()?;
+
+        Ok(Problem {
+            patterns,
+            designs,
+        })
+    }
+
+    /// Number of designs that can be made from the available patterns.
+    pub fn solve_part1(&self) -> usize {
+        let mut result = 0;
+        let mut memory = HashMap::new();
+        for design in self.designs.iter() {
+            if self.available_combinations(design, &mut memory) > 0 {
+                result += 1;
+            }
+        }
+        result
+    }
+
+    /// Total number of ways every design can be made.
+    pub fn solve_part2(&self) -> usize {
+        let mut result = 0;
+        let mut memory = HashMap::new();
+        for design in self.designs.iter() {
+            result += self.available_combinations(design, &mut memory);
+        }
+        result
+    }
+    
+    fn available_combinations(&self, design: &str, memory: &mut HashMap<String, usize>) -> usize {
+        if design.is_empty() {
+            return 1;
+        }
+        if let Some(&result) = memory.get(design) {
+            return result;
+        }
+
+        let mut result = 0;
+        for pattern in self.patterns.iter() {
+            if let Some(rest) = design.strip_prefix(pattern) {
+                result += self.available_combinations(rest, memory);
+            }
+        }
+
+        memory.insert(design.to_string(), result);
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "
+        r, wr, b, g, bwu, rb, gb, br
+
+        brwrr
+        bggr
+        gbbr
+        rrbgbr
+        ubwu
+        bwurrg
+        brgr
+        bbrgwb
+    ";
+
+    #[test]
+    fn test_part1_ex1() {
+        let input = Day19::parse(INPUT).unwrap();
+
+        assert_eq!(Day19::part1(&input), 6);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = Day19::parse(INPUT).unwrap();
+
+        assert_eq!(Day19::part2(&input), 16);
+    }
+}
@@ -0,0 +1,104 @@
+use util::{ParseError, Solution, Source};
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Vec<i32>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        let source = Source::new(data);
+
+        source.parse_lines(|line| {
+            line.split_ascii_whitespace()
+                .map(|s| source.parse(s, "a level"))
+                .collect()
+        })
+    }
+
+    fn part1(reports: &Self::Input) -> usize {
+        reports.iter().filter(|levels| is_safe(levels)).count()
+    }
+
+    fn part2(reports: &Self::Input) -> usize {
+        reports.iter().filter(|levels| is_safe_with_dampener(levels)).count()
+    }
+}
+
+/// A report is safe when its levels are strictly increasing or decreasing
+/// by 1 to 3 at each step.
+pub fn is_safe(levels: &[i32]) -> bool {
+    let mut diff = 0;
+
+    levels.windows(2).all(|w| {
+        let first = w.first().unwrap();
+        let second = w.get(1).unwrap();
+        let d = first - second;
+        
+        if (d.abs() < 1) || (d.abs() > 3) || (d < 0 && diff > 0) || (d > 0 && diff < 0) {
+            return false;
+        }
+
+        diff = d;
+
+        true
+    })
+}
+
+/// Like [`is_safe`], but tolerates a single bad level.
+pub fn is_safe_with_dampener(levels: &[i32]) -> bool {
+    if is_safe(levels) {
+        return true;
+    }
+
+    for i in 0..levels.len() {
+        let mut v = Vec::new();
+        v.extend_from_slice(&levels[..i]);
+        v.extend_from_slice(&levels[i+1..]);
+        if is_safe(&v) {
+            return true;
+        }
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "
+        7 6 4 2 1
+        1 2 7 8 9
+        9 7 6 2 1
+        1 3 2 4 5
+        8 6 4 4 1
+        1 3 6 7 9
+    ";
+
+    #[test]
+    fn test_part1() {
+        let input = Day2::parse(INPUT).unwrap();
+
+        assert_eq!(Day2::part1(&input), 2);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = Day2::parse(INPUT).unwrap();
+
+        assert_eq!(Day2::part2(&input), 4);
+    }
+
+    #[test]
+    fn test_part2_edge_case() {
+        const EDGE_CASE: &str = "
+            7 8 4 2 1
+            3 10 2 1
+        ";
+        let input = Day2::parse(EDGE_CASE).unwrap();
+
+        assert_eq!(Day2::part2(&input), 2);
+    }
+}
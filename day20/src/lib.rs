@@ -0,0 +1,175 @@
+use std::collections::{HashMap, HashSet};
+
+use util::{search, ParseError, Solution, Source};
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Problem;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        Problem::parse(data)
+    }
+
+    fn part1(problem: &Self::Input) -> usize {
+        problem.solve(2, |v| v >= 100)
+    }
+
+    fn part2(problem: &Self::Input) -> usize {
+        problem.solve(20, |v| v >= 100)
+    }
+}
+
+/// The racetrack with its start and end positions.
+pub struct Problem {
+    walls: HashSet<(i32, i32)>,
+    start: (i32, i32),
+    end: (i32, i32),
+}
+
+impl Problem {
+    pub fn parse(data: &str) -> Result<Self, ParseError> {
+        let source = Source::new(data);
+        let lines = source.lines().collect::<Vec<_>>();
+
+        let mut walls = HashSet::new();
+        let mut start = None;
+        let mut end = None;
+        for (y, line) in lines.iter().enumerate() {
+            for (x, c) in line.char_indices() {
+                let pos = (x as i32, y as i32);
+
+                if c == '#' {
+                    walls.insert(pos);
+                } else if c == 'S' {
+                    start = Some(pos);
+                } else if c == 'E' {
+                    end = Some(pos);
+                } else if c != '.' {
+                    return Err(source.char_error(line, x, "'#', '.', 'S' or 'E'"));
+                }
+            }
+
+        }
+        Ok(Problem {
+            walls,
+            start: start.ok_or_else(|| source.missing_after(data.trim_end(), "a start tile ('S')"))?,
+            end: end.ok_or_else(|| source.missing_after(data.trim_end(), "an end tile ('E')"))?,
+        })
+    }
+
+    /// Counts the cheats of at most `cheat_size` picoseconds whose time saved
+    /// satisfies `p`.
+    pub fn solve<P: Fn(usize) -> bool>(&self, cheat_size: i32, p: P) -> usize {
+        let race = self.find_path();
+        let positions = race.iter().enumerate().map(|(i, &pos)| {
+            (pos, i)
+        }).collect::<HashMap<(i32, i32), usize>>();
+
+        let mut cheats = HashSet::new();
+        for dx in 0..=cheat_size {
+            for dy in 0..=(cheat_size-dx) {
+                cheats.insert((dx, dy));
+                cheats.insert((-dx, dy));
+                cheats.insert((dx, -dy));
+                cheats.insert((-dx, -dy));
+            }
+        }
+
+        let mut count = 0;
+        for (i, (x, y)) in race.iter().enumerate() {
+            // Let's see how far ahead we get if we cheat here.
+
+            for &(dx, dy) in cheats.iter() {
+                let next = (x+dx, y+dy);
+                if let Some(&next_i) = positions.get(&next) {
+                    if next_i < i {
+                        continue;
+                    }
+
+                    let gain = next_i - i - (dx.unsigned_abs() as usize) - (dy.unsigned_abs() as usize);
+                    if p(gain) {
+                        count += 1;
+                    }
+                }
+            }
+        }
+        
+        count
+    }
+
+    fn find_path(&self) -> Vec<(i32, i32)> {
+        let successors = |&(x, y): &(i32, i32)| {
+            [(x, y + 1), (x, y - 1), (x + 1, y), (x - 1, y)]
+                .into_iter()
+                .filter(|next| !self.walls.contains(next))
+                .collect::<Vec<_>>()
+        };
+
+        let race = search::bfs(self.start, successors, |&pos| pos == self.end);
+
+        race.path(&self.end).expect("the track leads to the end")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "
+        ###############
+        #...#...#.....#
+        #.#.#.#.#.###.#
+        #S#...#.#.#...#
+        #######.#.#.###
+        #######.#.#...#
+        #######.#.###.#
+        ###..E#...#...#
+        ###.#######.###
+        #...###...#...#
+        #.#####.#.###.#
+        #.#...#.#.#...#
+        #.#.#.#.#.#.###
+        #...#...#...###
+        ###############
+    ";
+
+    #[test]
+    fn test_part1_ex1() {
+        let problem = Problem::parse(INPUT).unwrap();
+        assert_eq!(problem.find_path().len(), 85);
+        assert_eq!(problem.solve(2, |v| v == 2), 14);
+        assert_eq!(problem.solve(2, |v| v == 4), 14);
+        assert_eq!(problem.solve(2, |v| v == 6), 2);
+        assert_eq!(problem.solve(2, |v| v == 8), 4);
+        assert_eq!(problem.solve(2, |v| v == 10), 2);
+        assert_eq!(problem.solve(2, |v| v == 12), 3);
+        assert_eq!(problem.solve(2, |v| v == 20), 1);
+        assert_eq!(problem.solve(2, |v| v == 36), 1);
+        assert_eq!(problem.solve(2, |v| v == 38), 1);
+        assert_eq!(problem.solve(2, |v| v == 40), 1);
+        assert_eq!(problem.solve(2, |v| v == 64), 1);
+    }
+
+    #[test]
+    fn test_part2() {
+        let problem = Problem::parse(INPUT).unwrap();
+        assert_eq!(problem.find_path().len(), 85);
+        assert_eq!(problem.solve(20, |v| v == 50), 32);
+        assert_eq!(problem.solve(20, |v| v == 52), 31);
+        assert_eq!(problem.solve(20, |v| v == 54), 29);
+        assert_eq!(problem.solve(20, |v| v == 56), 39);
+        assert_eq!(problem.solve(20, |v| v == 58), 25);
+        assert_eq!(problem.solve(20, |v| v == 60), 23);
+        assert_eq!(problem.solve(20, |v| v == 62), 20);
+        assert_eq!(problem.solve(20, |v| v == 64), 19);
+        assert_eq!(problem.solve(20, |v| v == 66), 12);
+        assert_eq!(problem.solve(20, |v| v == 68), 14);
+        assert_eq!(problem.solve(20, |v| v == 70), 12);
+        assert_eq!(problem.solve(20, |v| v == 72), 22);
+        assert_eq!(problem.solve(20, |v| v == 74), 4);
+        assert_eq!(problem.solve(20, |v| v == 76), 3);
+    }
+}
\ No newline at end of file
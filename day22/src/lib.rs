@@ -0,0 +1,201 @@
+use std::collections::HashMap;
+
+use util::{ParseError, Solution, Source};
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = Vec<usize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        let source = Source::new(data);
+
+        source.parse_lines(|l| source.parse(l, "a secret number"))
+    }
+
+    fn part1(buyers: &Self::Input) -> usize {
+        let mut result = 0;
+        for v in buyers.iter() {
+            let mut rnd = Random { secret: *v };
+            for _ in 0..2000 {
+                rnd.next();
+            }
+            result += rnd.secret;
+        }
+
+        result
+    }
+
+    fn part2(buyers: &Self::Input) -> usize {
+        let secrets: Vec<_> = buyers.iter().map(|b| {
+            Random{ secret: *b }.generate(2000)
+        }).collect();
+
+        let prices: Vec<_> = secrets.iter().map(|s| {
+            prices(s)
+        }).collect();
+
+        let changes: Vec<_> = prices.iter().map(|p| {
+            changes(p)
+        }).collect();
+
+        let sequences: Vec<_> = buyers.iter().enumerate().map(|(i, _)| {
+            build_sequences(&prices[i], &changes[i])
+        }).collect();
+
+        let mut total_bananas = Vec::new();
+        for i in 0..buyers.len() {
+            for (w1, &v1) in sequences[i].iter() {
+                let mut total = v1;
+
+                for (j, other) in sequences.iter().enumerate().skip(1) {
+                    if j == i {
+                        continue;
+                    }
+
+                    if let Some(v2) = other.get(w1) {
+                        total += v2;
+                    }
+                }
+
+                total_bananas.push(total);
+            }
+        }
+
+
+        *total_bananas.iter().max().unwrap()
+    }
+}
+
+fn prices(secrets: &[usize]) -> Vec<usize> {
+    secrets.iter().map(|s| s % 10).collect()
+}
+
+fn changes(prices: &[usize]) -> Vec<i32> {
+    prices
+        .windows(2)
+        .map(|w| (*w.last().unwrap() as i32) - (*w.first().unwrap() as i32))
+        .collect()
+}
+
+fn build_sequences(prices: &[usize], changes: &[i32]) -> HashMap<Vec<i32>, usize> {
+    let mut result = HashMap::new();
+    for (i, w) in changes.windows(4).enumerate() {
+        if !result.contains_key(w) {
+            result.insert(w.to_vec(), prices[i+4]);
+        }
+    }
+    result
+}
+
+/// A buyer's pseudorandom secret number generator.
+pub struct Random {
+    secret: usize,
+}
+
+impl Random {
+    pub fn new(secret: usize) -> Self {
+        Random { secret }
+    }
+
+    /// Returns the current secret followed by the next `count` secrets.
+    pub fn generate(&mut self, count: usize) -> Vec<usize> {
+        let mut result = Vec::new();
+        result.push(self.secret);
+        for _ in 0..count {
+            self.next();
+            result.push(self.secret);
+        }
+        result
+    }
+
+    fn next(&mut self) -> usize {
+        let a = self.secret * 64;
+        self.mix(a);
+        self.prune();
+
+        let b = self.secret / 32;
+        self.mix(b);
+        self.prune();
+
+        let c = self.secret * 2048;
+        self.mix(c);
+        self.prune();
+
+        self.secret
+    }
+
+    fn mix(&mut self, v: usize) {
+        let r = self.secret ^ v;
+        self.secret = r;
+    }
+
+    fn prune(&mut self) {
+        self.secret %= 16777216;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "
+        1
+        10
+        100
+        2024
+    ";
+
+    #[test]
+    fn test_random() {
+        let mut random = Random { secret: 123 };
+        assert_eq!(random.next(), 15887950);
+        assert_eq!(random.next(), 16495136);
+        assert_eq!(random.next(), 527345);
+        assert_eq!(random.next(), 704524);
+        assert_eq!(random.next(), 1553684);
+        assert_eq!(random.next(), 12683156);
+        assert_eq!(random.next(), 11100544);
+        assert_eq!(random.next(), 12249484);
+        assert_eq!(random.next(), 7753432);
+        assert_eq!(random.next(), 5908254);
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = Day22::parse(INPUT).unwrap();
+
+        assert_eq!(Day22::part1(&input), 37327623);
+    }
+
+    #[test]
+    fn test_part2() {
+        const INPUT: &str = "
+            1
+            2
+            3
+            2024
+        ";
+        let input = Day22::parse(INPUT).unwrap();
+
+        assert_eq!(Day22::part2(&input), 23);
+    }
+
+    #[test]
+    fn test_prices() {
+        let mut rnd = Random { secret: 123 };
+        let secrets = rnd.generate(10);
+        let prices = prices(&secrets);
+        let changes = changes(&prices);
+
+        for i in 0..secrets.len() {
+            print!("{:10} {}", secrets[i], prices[i]);
+            if i > 0 {
+                print!(" ({})", changes[i-1]);
+            }
+            println!();
+        }
+    }
+}
@@ -0,0 +1,201 @@
+use std::collections::{HashMap, HashSet};
+
+use util::{ParseError, Solution, Source};
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Problem;
+    type Answer1 = usize;
+    type Answer2 = String;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        Problem::parse(data)
+    }
+
+    fn part1(problem: &Self::Input) -> usize {
+        problem.count_triads()
+    }
+
+    fn part2(problem: &Self::Input) -> String {
+        problem.largest_network().join(",")
+    }
+}
+
+/// The LAN, as a list of directly connected computers per computer.
+pub struct Problem {
+    computers: HashMap<String, Vec<String>>,
+}
+
+impl Problem {
+    pub fn parse(data: &str) -> Result<Self, ParseError> {
+        let source = Source::new(data);
+        
+        let pairs: Vec<_> = source.parse_lines(|l| {
+            let [a, b] = source.record(l, "-")?;
+            Ok((a.to_string(), b.to_string()))
+        })?;
+
+        let mut computers = HashMap::new();
+        for (a, b) in pairs.iter() {
+            computers.entry(a.clone()).or_insert(Vec::new()).push(b.clone());
+            computers.entry(b.clone()).or_insert(Vec::new()).push(a.clone());
+        }
+
+        Ok(Problem {
+            computers,
+        })
+    }
+
+    /// Number of sets of three interconnected computers where at least one
+    /// name starts with a `t`.
+    pub fn count_triads(&self) -> usize {
+        let mut triads = HashSet::new();
+        for (a, others) in self.computers.iter() {
+            for i in 0..others.len() {
+                let b = &others[i];
+                for c in others[i+1..].iter() {
+                    if self.computers[b].contains(c) {
+                        let mut triad = vec![a.clone(), b.clone(), c.clone()];
+                        triad.sort();
+                        triads.insert(triad);
+                    }
+                }
+            }
+        }
+
+        let mut result = 0;
+        for t in triads.iter() {
+            if t.iter().filter(|v|v.starts_with("t")).count() > 0 {
+                result += 1;
+            }
+        }
+        
+
+        result
+    }
+    
+    /// Names of the computers in the largest fully connected set, sorted.
+    pub fn largest_network(&self) -> Vec<String> {
+        let mut computers: Vec<&String> = self.computers.keys().collect();
+        computers.sort();
+
+        let mut largest = Vec::new();
+        self.grow(&mut Vec::new(), &computers, &mut largest);
+
+        largest.into_iter().cloned().collect()
+    }
+
+    /// Tries growing `network` with each of the `candidates`, which are all
+    /// connected to every computer in it, keeping the largest network found
+    /// so far in `largest`.
+    fn grow<'a>(
+        &'a self,
+        network: &mut Vec<&'a String>,
+        candidates: &[&'a String],
+        largest: &mut Vec<&'a String>,
+    ) {
+        if network.len() > largest.len() {
+            *largest = network.clone();
+        }
+
+        for (i, c) in candidates.iter().enumerate() {
+            // Not enough candidates left to beat the largest one.
+            if network.len() + candidates.len() - i <= largest.len() {
+                return;
+            }
+
+            let connected: Vec<&String> = candidates[i+1..]
+                .iter()
+                .filter(|other| self.computers[*c].contains(other))
+                .copied()
+                .collect();
+
+            network.push(c);
+            self.grow(network, &connected, largest);
+            network.pop();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "
+        kh-tc
+        qp-kh
+        de-cg
+        ka-co
+        yn-aq
+        qp-ub
+        cg-tb
+        vc-aq
+        tb-ka
+        wh-tc
+        yn-cg
+        kh-ub
+        ta-co
+        de-co
+        tc-td
+        tb-wq
+        wh-td
+        ta-ka
+        td-qp
+        aq-cg
+        wq-ub
+        ub-vc
+        de-ta
+        wq-aq
+        wq-vc
+        wh-yn
+        ka-de
+        kh-ta
+        co-tc
+        wh-qp
+        tb-vc
+        td-yn
+    ";
+
+    #[test]
+    fn test_part1_ex1() {
+        let input = Day23::parse(INPUT).unwrap();
+
+        assert_eq!(Day23::part1(&input), 7);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = Day23::parse(INPUT).unwrap();
+
+        assert_eq!(Day23::part2(&input), "co,de,ka,ta");
+    }
+
+    #[test]
+    fn test_part2_not_greedy() {
+        // Growing a network from any one link by adding every computer that
+        // fits, in the order the links are listed, never finds all five.
+        let input = Day23::parse("
+            qe-qb
+            ke-qb
+            qb-qa
+            tf-qe
+            ta-qa
+            kf-qa
+            tb-qe
+            kf-ta
+            tf-qb
+            qe-ke
+            tf-tb
+            qe-qa
+            ta-qb
+            qe-kf
+            qb-tb
+            kf-qb
+            tb-kf
+            tb-qa
+        ").unwrap();
+
+        assert_eq!(Day23::part2(&input), "kf,qa,qb,qe,tb");
+    }
+}
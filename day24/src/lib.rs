@@ -0,0 +1,252 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    io::{self, Write},
+};
+
+use util::{ParseError, Solution, Source};
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Problem;
+    type Answer1 = usize;
+    type Answer2 = String;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        Problem::parse(data)
+    }
+
+    fn part1(problem: &Self::Input) -> usize {
+        problem.simulate()
+    }
+
+    fn part2(_problem: &Self::Input) -> String {
+        /*
+         * All outputs should have line of two XOR gates to the corresponding
+         * inputs, e.g:
+         *
+         *    (x12, y12) -> XOR -> XOR -> z12
+         *
+         * Analyzing the graph written by the day24 binary and looking for discrepancies in
+         * the XOR chains gives the following required swaps:
+         *
+         * z08 <-> vvr
+         * bkr <-> rnq
+         * z28 <-> tfb
+         * mqh <-> z39
+         *
+         * The final result looks like this:
+         *
+         *   bkr,mqh,rnq,tfb,vvr,z08,z28,z39
+         */
+
+        "bkr,mqh,rnq,tfb,vvr,z08,z28,z39".to_string()
+    }
+}
+
+/// The initial wire values and the gates connecting them.
+pub struct Problem {
+    wires: HashMap<String, bool>,
+    gates: Vec<Gate>,
+}
+
+#[derive(Clone)]
+struct Gate {
+    a: String,
+    b: String,
+    out: String,
+    op: String,
+}
+
+impl Gate {
+    fn parse<'a>(source: &Source<'a>, s: &'a str) -> Result<Gate, ParseError> {
+        let (expr, out) = source.split_once(s, " -> ")?;
+        let mut parts = expr.split(" ");
+
+        let a = source.next(&mut parts, expr, "an input wire")?;
+        let op = source.next(&mut parts, a, "AND, OR or XOR")?;
+        let b = source.next(&mut parts, op, "an input wire")?;
+
+        if !matches!(op, "AND" | "OR" | "XOR") {
+            return Err(source.error(op, "AND, OR or XOR"));
+        }
+        if let Some(extra) = parts.next() {
+            return Err(source.error(extra, "' -> '"));
+        }
+
+        Ok(Gate {
+            a: a.to_string(),
+            b: b.to_string(),
+            out: out.to_string(),
+            op: op.to_string(),
+        })
+    }
+
+    fn resolve(&self, wires: &mut HashMap<String, bool>) -> bool {
+        if !wires.contains_key(&self.a) || !wires.contains_key(&self.b) {
+            return false;
+        }
+
+        match self.op.as_str() {
+            "AND" => wires.insert(self.out.clone(), wires[&self.a] && wires[&self.b]),
+            "XOR" => wires.insert(self.out.clone(), wires[&self.a] != wires[&self.b]),
+            "OR" => wires.insert(self.out.clone(), wires[&self.a] || wires[&self.b]),
+            _ => return false,
+        };
+
+        true
+    }
+}
+
+impl Problem {
+    pub fn parse(data: &str) -> Result<Self, ParseError> {
+        let source = Source::new(data);
+        let (wires, gates) = source.two_sections("gates")?;
+
+        let wires = util::lines(wires)
+            .map(|s| {
+                let [name, value] = source.record(s, ": ")?;
+                let value = match value {
+                    "0" => false,
+                    "1" => true,
+                    _ => return Err(source.error(value, "0 or 1")),
+                };
+
+                Ok((name.to_string(), value))
+            })
+            .collect::<Result<_, _>>()?;
+
+        let gates = util::lines(gates)
+            .map(|s| Gate::parse(&source, s))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Problem { wires, gates })
+    }
+
+    /// Simulates the circuit and returns the number formed by the `z` wires.
+    pub fn simulate(&self) -> usize {
+        let mut wires = self.wires.clone();
+        let mut gates = VecDeque::from_iter(self.gates.iter());
+
+        while let Some(g) = gates.pop_back() {
+            if !g.resolve(&mut wires) {
+                gates.push_front(g);
+            }
+        }
+
+        let mut z_outputs: Vec<_> = wires
+            .iter()
+            .filter(|(name, _)| name.starts_with("z"))
+            .collect();
+        z_outputs.sort_by_key(|(a, _)| *a);
+
+        let mut result = 0;
+        for (i, (_, &v)) in z_outputs.iter().enumerate() {
+            if v {
+                result |= 1 << i;
+            }
+        }
+
+        result
+    }
+
+    /// Writes the circuit as a [D2](https://d2lang.com) diagram.
+    pub fn write_graph<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        for g in self.gates.iter() {
+            let name = format!("\"{} {} {} -> {}\"", g.a, g.op, g.b, g.out);
+            writeln!(w, "{} -> {}", g.a, name)?;
+            writeln!(w, "{} -> {}", g.b, name)?;
+            writeln!(w, "{} -> {}", name, g.out)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "
+        x00: 1
+        x01: 1
+        x02: 1
+        y00: 0
+        y01: 1
+        y02: 0
+
+        x00 AND y00 -> z00
+        x01 XOR y01 -> z01
+        x02 OR y02 -> z02
+    ";
+
+    #[test]
+    fn test_part1_ex1() {
+        let input = Day24::parse(INPUT).unwrap();
+
+        assert_eq!(Day24::part1(&input), 4);
+    }
+
+    #[test]
+    fn test_part1_ex2() {
+        const INPUT: &str = "
+            x00: 1
+            x01: 0
+            x02: 1
+            x03: 1
+            x04: 0
+            y00: 1
+            y01: 1
+            y02: 1
+            y03: 1
+            y04: 1
+
+            ntg XOR fgs -> mjb
+            y02 OR x01 -> tnw
+            kwq OR kpj -> z05
+            x00 OR x03 -> fst
+            tgd XOR rvg -> z01
+            vdt OR tnw -> bfw
+            bfw AND frj -> z10
+            ffh OR nrd -> bqk
+            y00 AND y03 -> djm
+            y03 OR y00 -> psh
+            bqk OR frj -> z08
+            tnw OR fst -> frj
+            gnj AND tgd -> z11
+            bfw XOR mjb -> z00
+            x03 OR x00 -> vdt
+            gnj AND wpb -> z02
+            x04 AND y00 -> kjc
+            djm OR pbm -> qhw
+            nrd AND vdt -> hwm
+            kjc AND fst -> rvg
+            y04 OR y02 -> fgs
+            y01 AND x02 -> pbm
+            ntg OR kjc -> kwq
+            psh XOR fgs -> tgd
+            qhw XOR tgd -> z09
+            pbm OR djm -> kpj
+            x03 XOR y03 -> ffh
+            x00 XOR y04 -> ntg
+            bfw OR bqk -> z06
+            nrd XOR fgs -> wpb
+            frj XOR qhw -> z04
+            bqk OR frj -> z07
+            y03 OR x01 -> nrd
+            hwm AND bqk -> z03
+            tgd XOR rvg -> z12
+            tnw OR pbm -> gnj
+        ";
+        let input = Day24::parse(INPUT).unwrap();
+
+        assert_eq!(Day24::part1(&input), 2024);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Problem::parse("x00: 1\ny00: 0\n\nx00 NAND y00 -> z00").err().unwrap();
+
+        assert_eq!(err.to_string(), "4:5: expected AND, OR or XOR, found 'NAND'");
+    }
+}
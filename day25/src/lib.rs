@@ -0,0 +1,188 @@
+use util::{ParseError, Solution, Source};
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = Problem;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        Problem::parse(data)
+    }
+
+    fn part1(problem: &Self::Input) -> usize {
+        problem.solve()
+    }
+
+    fn part2(_problem: &Self::Input) -> usize {
+        // There is no second puzzle on the last day: its star is given for
+        // having all 49 others, so there is nothing to compute. 0 is what
+        // the part 2 test has expected from the start.
+        0
+    }
+}
+
+/// Pin heights of the keys and locks in the schematics.
+pub struct Problem {
+    keys: Vec<Vec<usize>>,
+    locks: Vec<Vec<usize>>,
+}
+
+impl Problem {
+    pub fn parse(data: &str) -> Result<Self, ParseError> {
+        let source = Source::new(data);
+
+        let mut keys = Vec::new();
+        let mut locks = Vec::new();
+
+        for s in source.sections() {
+            let lines: Vec<_> = util::lines(s).collect();
+            if lines.len() != 7 {
+                return Err(source.error(s, "a schematic of 7 rows"));
+            }
+            for line in lines.iter() {
+                if line.len() != 5 {
+                    return Err(source.error(line, "a row of 5 pins"));
+                }
+                if let Some(i) = line.find(|c| c != '#' && c != '.') {
+                    return Err(source.char_error(line, i, "'#' or '.'"));
+                }
+            }
+
+            match (lines[0], lines[6]) {
+                ("#####", ".....") => locks.push(parse_lock(&lines)),
+                (".....", "#####") => keys.push(parse_key(&lines)),
+                ("#####", bottom) => return Err(source.error(bottom, "'.....' below a lock")),
+                (".....", bottom) => return Err(source.error(bottom, "'#####' below a key")),
+                (top, _) => return Err(source.error(top, "'#####' or '.....'")),
+            }
+        }
+
+        Ok(Problem {
+            keys,
+            locks,
+        })
+    }
+
+    /// Number of key/lock pairs that fit together without overlapping.
+    pub fn solve(&self) -> usize {
+        let mut result = 0;
+
+        for key in self.keys.iter() {
+            for lock in self.locks.iter() {
+                if key_matches(key, lock) {
+                    result += 1;
+                }
+            }
+        }
+
+        result
+    }
+}
+
+fn parse_lock(l: &[&str]) -> Vec<usize> {
+    let mut heights = vec![0, 0, 0, 0, 0];
+    for (x, height) in heights.iter_mut().enumerate() {
+        for (y, line) in l.iter().enumerate() {
+            let c = line.chars().nth(x).unwrap();
+            if c != '#' {
+                *height = y - 1;
+                break;
+            }
+        }
+    }
+    heights
+}
+
+fn parse_key(l: &[&str]) -> Vec<usize> {
+    let mut heights = vec![0, 0, 0, 0, 0];
+    for (x, height) in heights.iter_mut().enumerate() {
+        for (y, line) in l.iter().enumerate().rev() {
+            let c = line.chars().nth(x).unwrap();
+            if c != '#' {
+                *height = 5 - y;
+                break;
+            }
+        }
+    }
+    heights
+}
+
+fn key_matches(key: &[usize], lock: &[usize]) -> bool {
+    for i in 0..key.len() {
+        if key[i] + lock[i] > 5 {
+            return false;
+        }
+    }
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "
+        #####
+        .####
+        .####
+        .####
+        .#.#.
+        .#...
+        .....
+
+        #####
+        ##.##
+        .#.##
+        ...##
+        ...#.
+        ...#.
+        .....
+
+        .....
+        #....
+        #....
+        #...#
+        #.#.#
+        #.###
+        #####
+
+        .....
+        .....
+        #.#..
+        ###..
+        ###.#
+        ###.#
+        #####
+
+        .....
+        .....
+        .....
+        #....
+        #.#..
+        #.#.#
+        #####
+    ";
+
+    #[test]
+    fn test_part1_ex1() {
+        let input = Day25::parse(INPUT).unwrap();
+
+        assert_eq!(Day25::part1(&input), 3);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = Day25::parse(INPUT).unwrap();
+
+        assert_eq!(Day25::part2(&input), 0);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day25::parse(".....\n#....\n#....\n#....\n#....\n#....\n....#").err().unwrap();
+
+        assert_eq!(err.to_string(), "7:1: expected '#####' below a key, found '....#'");
+    }
+}
@@ -0,0 +1,119 @@
+use regex::Regex;
+use util::{ParseError, Solution, Source};
+
+pub struct Day3;
+
+/// An instruction found in the corrupted memory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Do,
+    Dont,
+    Mul(i64, i64),
+}
+
+impl Solution for Day3 {
+    type Input = Vec<Instruction>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        parse_instructions(data)
+    }
+
+    fn part1(instructions: &Self::Input) -> i64 {
+        sum_multiplications(instructions)
+    }
+
+    fn part2(instructions: &Self::Input) -> i64 {
+        sum_enabled_multiplications(instructions)
+    }
+}
+
+/// Finds every `do()`, `don't()` and `mul(a,b)` in the memory dump. The
+/// rest is corrupted by design, but the numbers of a `mul` must have 1 to 3
+/// digits.
+pub fn parse_instructions(data: &str) -> Result<Vec<Instruction>, ParseError> {
+    const PATTERN: &str = r"do\(\)|don't\(\)|mul\((\d+),(\d+)\)";
+
+    let re = Regex::new(PATTERN).unwrap();
+    let source = Source::new(data);
+    let number = |m: regex::Match| {
+        let digits = &data[m.range()];
+        if digits.len() > 3 {
+            return Err(source.error(digits, "a number of 1 to 3 digits"));
+        }
+        source.parse(digits, "a number of 1 to 3 digits")
+    };
+
+    re.captures_iter(data).map(|m| {
+        match (m.get(1), m.get(2)) {
+            (Some(a), Some(b)) => Ok(Instruction::Mul(number(a)?, number(b)?)),
+            _ if &m[0] == "do()" => Ok(Instruction::Do),
+            _ => Ok(Instruction::Dont),
+        }
+    }).collect()
+}
+
+/// Sums the products of every `mul(a,b)` instruction.
+pub fn sum_multiplications(instructions: &[Instruction]) -> i64 {
+    instructions.iter().map(|i| match i {
+        Instruction::Mul(a, b) => a * b,
+        _ => 0,
+    }).sum()
+}
+
+/// Like [`sum_multiplications`], but skips instructions between a `don't()`
+/// and the next `do()`.
+pub fn sum_enabled_multiplications(instructions: &[Instruction]) -> i64 {
+    let mut enabled = true;
+
+    instructions.iter().map(|i| match i {
+        Instruction::Do => {
+            enabled = true;
+            0
+        },
+        Instruction::Dont => {
+            enabled = false;
+            0
+        },
+        Instruction::Mul(a, b) if enabled => a * b,
+        _ => 0,
+    }).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        const INPUT: &str = "
+            xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))
+        ";
+        let input = Day3::parse(INPUT).unwrap();
+
+        assert_eq!(Day3::part1(&input), 161);
+    }
+
+    #[test]
+    fn test_part2() {
+        const INPUT: &str = "
+            xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))
+        ";
+
+        let input = Day3::parse(INPUT).unwrap();
+
+        assert_eq!(Day3::part2(&input), 48);
+    }
+
+    #[test]
+    fn test_parse_long_number() {
+        const INPUT: &str = "mul(2,4)\nxmul(12345678901234567890,2)";
+
+        let err = Day3::parse(INPUT).unwrap_err();
+
+        assert_eq!((err.line, err.column), (2, 6));
+        assert_eq!(err.text, "12345678901234567890");
+        assert_eq!(err.expected, "a number of 1 to 3 digits");
+    }
+}
@@ -0,0 +1,88 @@
+use util::{Grid, ParseError, Pos, Solution, ALL_DIRECTIONS};
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Grid<char>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse(data)
+    }
+
+    fn part1(grid: &Self::Input) -> usize {
+        grid.find_all(&'X')
+            .map(|pos| {
+                ALL_DIRECTIONS
+                    .iter()
+                    .filter(|&&d| grid.ray(pos, d).map(|p| grid[p]).take(4).eq("XMAS".chars()))
+                    .count()
+            })
+            .sum()
+    }
+
+    fn part2(grid: &Self::Input) -> usize {
+        grid.find_all(&'A').filter(|&pos| find_x_mas(grid, pos)).count()
+    }
+}
+
+fn find_x_mas(grid: &Grid<char>, pos: Pos) -> bool {
+    let corner = |d| grid.step(pos, d).map(|p| grid[p]);
+
+    let down_left = matches!(
+        (corner((-1, -1)), corner((1, 1))),
+        (Some('M'), Some('S')) | (Some('S'), Some('M'))
+    );
+
+    let down_right = matches!(
+        (corner((1, -1)), corner((-1, 1))),
+        (Some('M'), Some('S')) | (Some('S'), Some('M'))
+    );
+
+    down_left && down_right
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        const INPUT: &str = "
+            MMMSXXMASM
+            MSAMXMSMSA
+            AMXSXMAAMM
+            MSAMASMSMX
+            XMASAMXAMM
+            XXAMMXXAMA
+            SMSMSASXSS
+            SAXAMASAAA
+            MAMMMXMMMM
+            MXMXAXMASX
+        ";
+        let input = Day4::parse(INPUT).unwrap();
+
+        assert_eq!(Day4::part1(&input), 18);
+    }
+
+    #[test]
+    fn test_part2() {
+        const INPUT: &str = "
+            MMMSXXMASM
+            MSAMXMSMSA
+            AMXSXMAAMM
+            MSAMASMSMX
+            XMASAMXAMM
+            XXAMMXXAMA
+            SMSMSASXSS
+            SAXAMASAAA
+            MAMMMXMMMM
+            MXMXAXMASX
+        ";
+
+        let input = Day4::parse(INPUT).unwrap();
+
+        assert_eq!(Day4::part2(&input), 9);
+    }
+}
@@ -0,0 +1,195 @@
+use std::collections::HashMap;
+
+use util::{ParseError, Solution, Source};
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = (HashMap<u32, Vec<u32>>, Vec<Vec<u32>>);
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        let source = Source::new(data);
+
+        let mut rules = HashMap::new();
+        let mut updates = Vec::new();
+
+        let (rules_block, updates_block) = source.two_sections("updates")?;
+
+        // Parse the rules block
+        for line in util::lines(rules_block) {
+            let [key, value] = source.record(line, "|")?;
+            let key = source.parse(key, "a page number")?;
+            let value = source.parse(value, "a page number")?;
+            rules.entry(key).or_insert(Vec::new()).push(value);
+        }
+
+        // Parse the updates block
+        for line in util::lines(updates_block) {
+            let line = line
+                .split(",")
+                .map(|s| source.parse(s, "a page number"))
+                .collect::<Result<_, _>>()?;
+
+            updates.push(line);
+        }
+
+        Ok((rules, updates))
+    }
+
+    fn part1((rules, updates): &Self::Input) -> u32 {
+        let mut result = 0;
+
+        for update in updates {
+            if is_valid(rules, update) {
+                result += update[update.len() / 2];
+            }
+        }
+
+        result
+    }
+
+    fn part2((rules, updates): &Self::Input) -> u32 {
+        let mut invalid_updates = Vec::new();
+
+        for update in updates {
+            if !is_valid(rules, update) {
+                invalid_updates.push(update.clone());
+            }
+        }
+
+        let mut result = 0;
+        for update in invalid_updates {
+            let update = reorder(rules, &update);
+
+            result += update[update.len() / 2];
+        }
+
+        result
+    }
+}
+
+/// Checks that no page in `update` is printed after a page that must come
+/// after it, according to `rules`.
+pub fn is_valid(rules: &HashMap<u32, Vec<u32>>, update: &[u32]) -> bool {
+    for i in 0..update.len() {
+        let key = update[i];
+
+        if let Some(rules) = rules.get(&key) {
+            for page in rules {
+                if update[0..i].contains(page) {
+                    return false;
+                }
+            }
+        }
+    }
+
+    true
+}
+
+/// Puts `update` in the order `rules` give, by taking the pages one at a time,
+/// each time one that none of the pages left must come before.
+///
+/// Sorting with a comparator is not enough, since the rules need not say
+/// anything about two pages that are only ordered through a third one.
+pub fn reorder(rules: &HashMap<u32, Vec<u32>>, update: &[u32]) -> Vec<u32> {
+    let mut left = update.to_vec();
+    let mut ordered = Vec::new();
+
+    while !left.is_empty() {
+        let first = (0..left.len())
+            .find(|&i| {
+                !left
+                    .iter()
+                    .any(|page| rules.get(page).is_some_and(|after| after.contains(&left[i])))
+            })
+            .unwrap_or(0);
+
+        ordered.push(left.remove(first));
+    }
+
+    ordered
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "
+        47|53
+        97|13
+        97|61
+        97|47
+        75|29
+        61|13
+        75|53
+        29|13
+        97|29
+        53|29
+        61|53
+        97|53
+        61|29
+        47|13
+        75|47
+        97|75
+        47|61
+        75|61
+        47|29
+        75|13
+        53|13
+
+        75,47,61,53,29
+        97,61,53,29,13
+        75,29,13
+        75,97,47,61,53
+        61,13,29
+        97,13,75,29,47
+    ";
+
+    #[test]
+    fn test_part1() {
+        let input = Day5::parse(INPUT).unwrap();
+
+        assert_eq!(Day5::part1(&input), 143);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = Day5::parse(INPUT).unwrap();
+
+        assert_eq!(Day5::part2(&input), 123);
+    }
+
+    #[test]
+    fn test_reorder() {
+        // Only neighbouring pages have rules between them.
+        let (rules, _) = Day5::parse("35|80\n53|35\n80|43\n43|83\n\n53").unwrap();
+
+        assert_eq!(reorder(&rules, &[53, 43, 83, 80, 35]), vec![53, 35, 80, 43, 83]);
+    }
+
+    #[test]
+    fn test_part2_sparse_rules() {
+        // Sorting with a comparator that only knows the direct rules put
+        // this update in the order 53,43,83,35,80, with 83 in the middle.
+        let input = Day5::parse("
+            35|80
+            53|35
+            80|43
+            43|83
+
+            53,43,83,80,35
+        ").unwrap();
+
+        assert_eq!(Day5::part2(&input), 80);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day5::parse("47|53\n97-13\n\n75,47").unwrap_err();
+
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.expected, "'|'");
+    }
+}
@@ -0,0 +1,221 @@
+use std::collections::HashMap;
+
+use util::{
+    geom::{Direction, Point},
+    anim::{Animation, Frame, Sink},
+    image::{Palette, Render, Rgb},
+    Grid, ParseError, Pos, Solution, Source,
+};
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Map;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        let source = Source::new(data);
+        let lines = source.lines().collect::<Vec<_>>();
+
+        let height = lines.len() as i32;
+        let width = lines.first().map_or(0, |l| l.len()) as i32;
+
+        let mut obstructions = HashMap::new();
+        let mut guard = None;
+
+        for (y, line) in lines.iter().enumerate() {
+            for (x, c) in line.char_indices() {
+                let pos = Point::new(x as i32, y as i32);
+                match c {
+                    '#' => {
+                        obstructions.insert(pos, '#');
+                    }
+                    '^' => {
+                        guard = Some(pos);
+                    }
+                    '.' => (),
+                    _ => return Err(source.char_error(line, x, "'.', '#' or '^'")),
+                };
+            }
+        }
+
+        Ok(Map {
+            width,
+            height,
+            obstructions,
+            guard: guard.ok_or_else(|| source.missing_after(data.trim_end(), "a guard ('^')"))?,
+            guard_route: HashMap::new(),
+        })
+    }
+
+    fn part1(map: &Self::Input) -> usize {
+        let mut map = map.clone();
+        map.run();
+
+        map.visited()
+    }
+
+    fn part2(original: &Self::Input) -> usize {
+        let mut first = original.clone();
+        first.run();
+
+        let mut result = 0;
+        for &pos in first.guard_route.keys() {
+            if pos == original.guard || original.obstructions.contains_key(&pos) {
+                continue;
+            }
+
+            let mut map = original.clone();
+            map.obstructions.insert(pos, 'O');
+
+            if map.run() {
+                result += 1;
+            }
+        }
+
+        result
+    }
+}
+
+/// Both parts animate the guard's patrol of the unchanged lab.
+impl Animation for Day6 {
+    fn animate(map: &Map, _part: u8, sink: &mut dyn Sink) {
+        let mut map = map.clone();
+        let mut moves = 0;
+
+        sink.push(Frame::of(&map, "start"));
+        map.walk(|map| {
+            moves += 1;
+            let caption = format!("move {}, {} positions visited", moves, map.visited());
+            sink.push(Frame::of(map, caption))
+        });
+    }
+}
+
+/// The lab floor with its obstructions and the guard's patrol so far.
+#[derive(Clone)]
+pub struct Map {
+    width: i32,
+    height: i32,
+    obstructions: HashMap<Point, char>,
+    guard: Point,
+    guard_route: HashMap<Point, Direction>,
+}
+
+impl Map {
+    /// Moves the guard until it either leaves the map or starts walking in a
+    /// loop. Returns `true` if the guard got stuck in a loop.
+    pub fn run(&mut self) -> bool {
+        self.walk(|_| true)
+    }
+
+    /// Like [`Map::run`], calling `on_move` with the map after every move of
+    /// the guard. Returns `false` early if `on_move` does.
+    pub fn walk(&mut self, mut on_move: impl FnMut(&Map) -> bool) -> bool {
+        let mut direction = Direction::North;
+
+        loop {
+            let mut pos = self.guard;
+            if pos.x < 0 || pos.x >= self.width || pos.y < 0 || pos.y >= self.height {
+                return false;
+            } else if self.guard_route.get(&pos) == Some(&direction) {
+                return true;
+            }
+
+            if self.obstructions.contains_key(&pos) {
+                // Back up
+                pos -= direction.delta();
+                // Turn right
+                direction = direction.turn_right();
+            } else {
+                self.guard_route.insert(pos, direction);
+            }
+
+            // Move forward
+            self.guard = pos.step(direction);
+
+            if !on_move(self) {
+                return false;
+            }
+        }
+    }
+
+    /// Number of distinct positions the guard has visited.
+    pub fn visited(&self) -> usize {
+        self.guard_route.len()
+    }
+}
+
+impl Render for Map {
+    fn render(&self) -> Grid<char> {
+        let mut grid = Grid::new(self.width as usize, self.height as usize, '.');
+
+        for pos in self.guard_route.keys().filter_map(|p| p.to_pos()) {
+            if let Some(cell) = grid.get_mut(pos) {
+                *cell = 'X';
+            }
+        }
+        if let Some(cell) = self.guard.to_pos().and_then(|p| grid.get_mut(p)) {
+            *cell = '^';
+        }
+        for (pos, &c) in self.obstructions.iter() {
+            if let Some(cell) = pos.to_pos().and_then(|p| grid.get_mut(p)) {
+                *cell = c;
+            }
+        }
+
+        grid
+    }
+
+    fn palette(&self) -> Palette {
+        Palette::new(Rgb::BLACK)
+            .with('#', Rgb::GRAY)
+            .with('O', Rgb::BLUE)
+            .with('X', Rgb::YELLOW)
+            .with('^', Rgb::RED)
+    }
+
+    fn highlight(&self) -> Vec<Pos> {
+        self.guard.to_pos().into_iter().collect()
+    }
+}
+
+impl std::fmt::Debug for Map {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.render())?;
+        writeln!(f, "{}", self.guard_route.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "
+        ....#.....
+        .........#
+        ..........
+        ..#.......
+        .......#..
+        ..........
+        .#..^.....
+        ........#.
+        #.........
+        ......#...
+    ";
+
+    #[test]
+    fn test_part1() {
+        let input = Day6::parse(INPUT).unwrap();
+
+        assert_eq!(Day6::part1(&input), 41);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = Day6::parse(INPUT).unwrap();
+
+        assert_eq!(Day6::part2(&input), 6);
+    }
+}
@@ -0,0 +1,118 @@
+use util::{ParseError, Solution, Source};
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Vec<(i64, Vec<i64>)>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        let source = Source::new(data);
+
+        source.parse_lines(|line| {
+            let [first, second] = source.record(line, ": ")?;
+            let answer = source.parse(first, "a test value")?;
+            let operands = second
+                .split_whitespace()
+                .map(|op| source.parse(op, "an operand"))
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok((answer, operands))
+        })
+    }
+
+    fn part1(equations: &Self::Input) -> i64 {
+        const OPERATORS: [&str; 2] = ["+", "*"];
+
+        let mut result = 0;
+        for (answer, operands) in equations {
+            let head = *operands.first().unwrap();
+            let tail = &operands[1..];
+            for op in OPERATORS {
+                if calculate(head, op, tail, *answer, &OPERATORS) {
+                    result += answer;
+                    break;
+                }
+            }
+        }
+
+        result
+    }
+
+    fn part2(equations: &Self::Input) -> i64 {
+        const OPERATORS: [&str; 3] = ["+", "*", "||"];
+
+        let mut result = 0;
+        for (answer, operands) in equations {
+            let head = *operands.first().unwrap();
+            let tail = &operands[1..];
+            for op in OPERATORS {
+                if calculate(head, op, tail, *answer, &OPERATORS) {
+                    result += answer;
+                    break;
+                }
+            }
+        }
+
+        result
+    }
+}
+
+fn calculate(head: i64, operator: &'static str, tail: &[i64], answer: i64, operators: &[&'static str]) -> bool {
+    let next = match operator {
+        "+" => head + tail[0],
+        "*" => head * tail[0],
+        "||" => format!("{}{}", head, tail[0]).parse::<i64>().unwrap(),
+        _ => panic!("Invalid operator"),
+    };
+    let tail = &tail[1..];
+    if tail.is_empty() {
+        return next == answer;
+    }
+
+    for op in operators {
+        if calculate(next, op, tail, answer, operators) {
+            return true;
+        }
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "
+        190: 10 19
+        3267: 81 40 27
+        83: 17 5
+        156: 15 6
+        7290: 6 8 6 15
+        161011: 16 10 13
+        192: 17 8 14
+        21037: 9 7 18 13
+        292: 11 6 16 20
+    ";
+
+    #[test]
+    fn test_part1() {
+        let input = Day7::parse(INPUT).unwrap();
+
+        assert_eq!(Day7::part1(&input), 3749);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = Day7::parse(INPUT).unwrap();
+
+        assert_eq!(Day7::part2(&input), 11387);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day7::parse("190: 10 19\n3267: 81 x 27").unwrap_err();
+
+        assert_eq!(err.to_string(), "2:10: expected an operand, found 'x'");
+    }
+}
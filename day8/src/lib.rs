@@ -0,0 +1,192 @@
+use std::collections::{HashMap, HashSet};
+
+use util::{geom::Point, ParseError, Solution, Source};
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = City;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        City::parse(data)
+    }
+
+    fn part1(city: &Self::Input) -> usize {
+        let mut city = city.clone();
+
+        city.find_anti_nodes(false);
+
+        city.anti_node_count()
+    }
+
+    fn part2(city: &Self::Input) -> usize {
+        let mut city = city.clone();
+
+        city.find_anti_nodes(true);
+
+        city.anti_node_count()
+    }
+}
+
+/// A map of the antennas in the city.
+#[derive(Clone)]
+pub struct City {
+    antennas: HashMap<char, Vec<Point>>,
+    height: i32,
+    width: i32,
+    anti_nodes: HashSet<Point>,
+}
+
+impl City {
+    pub fn parse(data: &str) -> Result<City, ParseError> {
+        let source = Source::new(data);
+        let lines = source.lines().collect::<Vec<_>>();
+
+        let height = lines.len() as i32;
+        let width = lines.first().map_or(0, |l| l.len()) as i32;
+
+        let mut chars = HashSet::new();
+
+        let mut antennas = HashMap::new();
+        for (y, line) in lines.iter().enumerate() {
+            for (x, c) in line.char_indices() {
+                if !c.is_ascii_alphanumeric() && c != '.' {
+                    return Err(source.char_error(line, x, "'.' or an antenna frequency"));
+                }
+                if c != '.' {
+                    chars.insert(c);
+                    antennas
+                        .entry(c)
+                        .or_insert(Vec::new())
+                        .push(Point::new(x as i32, y as i32));
+                }
+            }
+        }
+
+        Ok(City {
+            antennas,
+            height,
+            width,
+            anti_nodes: HashSet::new(),
+        })
+    }
+
+    /// Finds the antinodes of every pair of antennas with the same frequency.
+    /// With `harmonics`, every grid position in line with a pair counts.
+    pub fn find_anti_nodes(&mut self, harmonics: bool) {
+        let mut anti_nodes = HashSet::new();
+        for (_c, points) in self.antennas.iter() {
+            for i in 0..points.len() {
+                for j in i + 1..points.len() {
+                    let p1 = points[i];
+                    let p2 = points[j];
+
+                    let delta = p2 - p1;
+
+                    if harmonics {
+                        let mut h1 = p1;
+                        while self.in_bounds(&h1) {
+                            anti_nodes.insert(h1);
+                            h1 -= delta;
+                        }
+
+                        let mut h2 = p2;
+                        while self.in_bounds(&h2) {
+                            anti_nodes.insert(h2);
+                            h2 += delta;
+                        }
+                    } else {
+                        let h1 = p1 - delta;
+                        if self.in_bounds(&h1) {
+                            anti_nodes.insert(h1);
+                        }
+                        let h2 = p2 + delta;
+                        if self.in_bounds(&h2) {
+                            anti_nodes.insert(h2);
+                        }
+                    }
+                }
+            }
+        }
+        self.anti_nodes = anti_nodes
+    }
+
+    /// Number of antinodes found by the last call to [`City::find_anti_nodes`].
+    pub fn anti_node_count(&self) -> usize {
+        self.anti_nodes.len()
+    }
+
+    fn in_bounds(&self, p: &Point) -> bool {
+        let Point { x, y } = *p;
+
+        x >= 0 && y >= 0 && x < self.width && y < self.height
+    }
+}
+
+impl std::fmt::Debug for City {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut rows = Vec::new();
+        for y in 0..self.height {
+            let mut row = Vec::new();
+            for x in 0..self.width {
+                if self.anti_nodes.contains(&Point::new(x, y)) {
+                    row.push('#');
+                } else {
+                    row.push('.');
+                }
+            }
+            rows.push(row);
+        }
+
+        for (c, points) in self.antennas.iter() {
+            for Point { x, y } in points {
+                rows[*y as usize][*x as usize] = *c;
+            }
+        }
+
+        for row in rows {
+            for c in row {
+                write!(f, "{}", c)?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "
+        ............
+        ........0...
+        .....0......
+        .......0....
+        ....0.......
+        ......A.....
+        ............
+        ............
+        ........A...
+        .........A..
+        ............
+        ............
+    ";
+
+    #[test]
+    fn test_part1() {
+        let input = Day8::parse(INPUT).unwrap();
+
+        assert_eq!(Day8::part1(&input), 14);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = Day8::parse(INPUT).unwrap();
+
+        assert_eq!(Day8::part2(&input), 34);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,407 @@
+use std::fmt::Debug;
+
+use util::{
+    anim::{Animation, Frame, Sink},
+    image::{Palette, Render, Rgb},
+    Grid, ParseError, Pos, Solution, Source,
+};
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<u32>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        let source = Source::new(data);
+        let disk_map = data.trim();
+
+        disk_map
+            .char_indices()
+            .map(|(i, c)| c.to_digit(10).ok_or_else(|| source.char_error(disk_map, i, "a digit")))
+            .collect()
+    }
+
+    fn part1(disk_map: &Self::Input) -> u64 {
+        let mut disk = Disk::new(disk_map);
+
+        disk.reallocate();
+
+        disk.checksum()
+    }
+
+    fn part2(disk_map: &Self::Input) -> u64 {
+        let mut disk = SizedDisk::new(disk_map);
+
+        disk.reallocate();
+
+        disk.checksum()
+    }
+}
+
+impl Animation for Day9 {
+    fn animate(disk_map: &Vec<u32>, part: u8, sink: &mut dyn Sink) {
+        let mut moves = 0;
+        let mut frame = |blocks, moved| {
+            let caption = format!("{} moves", moves);
+            moves += 1;
+            sink.push(Frame::of(&Layout { blocks, moved }, caption))
+        };
+
+        if part == 1 {
+            let mut disk = Disk::new(disk_map);
+            if frame(disk.layout(), None) {
+                disk.reallocate_with(|disk, id| frame(disk.layout(), Some(id)));
+            }
+        } else {
+            let mut disk = SizedDisk::new(disk_map);
+            if frame(disk.layout(), None) {
+                disk.reallocate_with(|disk, id| frame(disk.layout(), Some(id)));
+            }
+        }
+    }
+}
+
+/// The blocks of a disk, drawn a hundred to a row with each file as the
+/// last digit of its id.
+struct Layout {
+    blocks: Vec<Option<u32>>,
+    /// The file that was just moved.
+    moved: Option<u32>,
+}
+
+impl Layout {
+    const WIDTH: usize = 100;
+
+    fn pos(i: usize) -> (usize, usize) {
+        (i % Self::WIDTH, i / Self::WIDTH)
+    }
+}
+
+impl Render for Layout {
+    fn render(&self) -> Grid<char> {
+        let height = self.blocks.len().div_ceil(Self::WIDTH);
+        let mut grid = Grid::new(Self::WIDTH, height, ' ');
+
+        for (i, block) in self.blocks.iter().enumerate() {
+            grid[Self::pos(i)] = match block {
+                Some(id) => char::from_digit(id % 10, 10).unwrap(),
+                None => '.',
+            };
+        }
+
+        grid
+    }
+
+    fn palette(&self) -> Palette {
+        const COLORS: [Rgb; 10] = [
+            Rgb::RED,
+            Rgb::GREEN,
+            Rgb::BLUE,
+            Rgb::YELLOW,
+            Rgb::BROWN,
+            Rgb(200, 80, 190),
+            Rgb(40, 190, 190),
+            Rgb(240, 130, 40),
+            Rgb(150, 120, 230),
+            Rgb(170, 210, 60),
+        ];
+
+        COLORS.iter().enumerate().fold(
+            Palette::new(Rgb::BLACK).with('.', Rgb::GRAY),
+            |palette, (digit, &color)| {
+                palette.with(char::from_digit(digit as u32, 10).unwrap(), color)
+            },
+        )
+    }
+
+    fn highlight(&self) -> Vec<Pos> {
+        self.blocks
+            .iter()
+            .enumerate()
+            .filter(|(_, block)| self.moved.is_some() && **block == self.moved)
+            .map(|(i, _)| Self::pos(i))
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Block {
+    Free,
+    File(u32),
+}
+
+/// A disk whose files can be split block by block.
+pub struct Disk {
+    blocks: Vec<Block>,
+}
+
+impl Disk {
+    /// Lays out the files described by a dense disk map.
+    pub fn new(disk_map: &[u32]) -> Disk {
+        let mut blocks = Vec::new();
+        let mut file = true;
+        let mut file_id = 0;
+
+        for &size in disk_map {
+            if file {
+                for _ in 0..size {
+                    blocks.push(Block::File(file_id));
+                }
+
+                file_id += 1;
+                file = false;
+            } else {
+                for _ in 0..size {
+                    blocks.push(Block::Free);
+                }
+                file = true;
+            }
+        }
+
+        Disk { blocks }
+    }
+
+    /// Compacts the disk by moving file blocks to the leftmost free space.
+    pub fn reallocate(&mut self) {
+        self.reallocate_with(|_, _| true);
+    }
+
+    /// Like [`Disk::reallocate`], calling `on_move` with the disk and the
+    /// file id after each block is moved. Stops early if it returns `false`.
+    pub fn reallocate_with(&mut self, mut on_move: impl FnMut(&Self, u32) -> bool) {
+        for i in (0..self.blocks.len()).rev() {
+            if let Block::File(id) = self.blocks[i] {
+                let next_free = &self.blocks[0..i].iter().position(|b| matches!(b, Block::Free));
+                if let Some(j) = next_free {
+                    self.blocks[*j] = Block::File(id);
+                    self.blocks[i] = Block::Free;
+                    if !on_move(self, id) {
+                        return;
+                    }
+                } else {
+                    // No more free blocks.
+                    return;
+                }
+            }
+        }
+    }
+
+    pub fn checksum(&self) -> u64 {
+        let mut result = 0;
+
+        for (i, block) in self.blocks.iter().enumerate() {
+            if let Block::File(id) = block {
+                result += (i as u64) * (*id as u64);
+            }
+        }
+
+        result
+    }
+
+    /// The file in each block, if any.
+    fn layout(&self) -> Vec<Option<u32>> {
+        self.blocks
+            .iter()
+            .map(|block| match block {
+                Block::Free => None,
+                Block::File(id) => Some(*id),
+            })
+            .collect()
+    }
+}
+
+impl Debug for Disk {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for block in &self.blocks {
+            match block {
+                Block::Free => write!(f, ".")?,
+                Block::File(id) => write!(f, "{}", id)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// A disk where files are only ever moved as a whole.
+pub struct SizedDisk {
+    blocks: Vec<SizedBlock>,
+}
+
+impl SizedDisk {
+    /// Lays out the files described by a dense disk map.
+    pub fn new(disk_map: &[u32]) -> SizedDisk {
+        let mut blocks = Vec::new();
+        let mut file = true;
+        let mut file_id = 0;
+
+        for &size in disk_map {
+            if file {
+                blocks.push(SizedBlock::File(file_id, size as usize));
+                file_id += 1;
+                file = false;
+            } else {
+                blocks.push(SizedBlock::Free{size: size as usize, files: vec![]});
+                file = true;
+            }
+        }
+
+        SizedDisk { blocks }
+    }
+
+    /// Compacts the disk by moving whole files to the leftmost free span
+    /// that fits them, trying each file once in order of decreasing id.
+    pub fn reallocate(&mut self) {
+        self.reallocate_with(|_, _| true);
+    }
+
+    /// Like [`SizedDisk::reallocate`], calling `on_move` with the disk and
+    /// the file id after each file is moved. Stops early if it returns
+    /// `false`.
+    pub fn reallocate_with(&mut self, mut on_move: impl FnMut(&Self, u32) -> bool) {
+        for i in (0..self.blocks.len()).rev() {
+            if let SizedBlock::File(id, size) = self.blocks[i] {
+                let next_free = &self.blocks[0..i].iter().position(|b| {
+                    if let SizedBlock::Free{size: free_size, files: _} = b {
+                        size <= *free_size
+                    } else {
+                        false
+                    }
+                });
+
+                if let Some(j) = next_free {
+                    if let SizedBlock::Free{size: free_size, files} = &mut self.blocks[*j] {
+                        files.push((id, size));
+                        *free_size -= size;
+                    }
+                    self.blocks[i] = SizedBlock::Free{size, files: vec![]};
+                    if !on_move(self, id) {
+                        return;
+                    }
+                }
+            }
+        }
+    }
+
+    pub fn checksum(&self) -> u64 {
+        let mut result = 0;
+        let mut pos = 0;
+
+        for block in self.blocks.iter() {
+            match block {
+                SizedBlock::File(id, size) => {
+                    for _ in 0..*size {
+                        result += (pos as u64) * (*id as u64);
+                        pos += 1;
+                    }
+                }
+                SizedBlock::Free{size, files} => {
+                    for (id, size) in files {
+                        for _ in 0..*size {
+                            result += (pos as u64) * (*id as u64);
+                            pos += 1;
+                        }
+                    }
+                    pos += size;
+                }
+            }
+        }
+
+        result
+    }
+
+    /// The file in each block, if any.
+    fn layout(&self) -> Vec<Option<u32>> {
+        let mut layout = Vec::new();
+
+        for block in &self.blocks {
+            match block {
+                SizedBlock::File(id, size) => layout.extend(vec![Some(*id); *size]),
+                SizedBlock::Free{size, files} => {
+                    for (id, size) in files {
+                        layout.extend(vec![Some(*id); *size]);
+                    }
+                    layout.extend(vec![None; *size]);
+                }
+            }
+        }
+
+        layout
+    }
+}
+
+impl Debug for SizedDisk {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for block in &self.blocks {
+            match block {
+                SizedBlock::Free{size, files} => {
+                    for (id, size) in files {
+                        for _ in 0..*size {
+                            write!(f, "{}", id)?;
+                        }
+                    }
+                    for _ in 0..*size {
+                        write!(f, ".")?;
+                    }
+                }
+                SizedBlock::File(id, size) => {
+                    for _ in 0..*size {
+                        write!(f, "{}", id)?;
+                    }
+                },
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+enum SizedBlock {
+    Free{
+        size: usize,
+        files: Vec<(u32, usize)>,
+    },
+    File(u32, usize),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "
+        2333133121414131402
+    ";
+
+    #[test]
+    fn test_part1() {
+        let input = Day9::parse(INPUT).unwrap();
+
+        assert_eq!(Day9::part1(&input), 1928);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = Day9::parse(INPUT).unwrap();
+
+        assert_eq!(Day9::part2(&input), 2858);
+    }
+
+    #[test]
+    fn test_animate() {
+        let input = Day9::parse(INPUT).unwrap();
+        let mut frames: Vec<Frame> = Vec::new();
+
+        Day9::animate(&input, 2, &mut frames);
+
+        // The layout before any move, then one after each of 9, 7, 4 and 2.
+        assert_eq!(frames.len(), 5);
+        assert_eq!(
+            frames[4].grid.row(0).collect::<String>().trim_end(),
+            "00992111777.44.333....5555.6666.....8888.."
+        );
+        assert_eq!(frames[4].highlight, vec![(4, 0)]);
+    }
+}
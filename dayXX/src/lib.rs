@@ -0,0 +1,58 @@
+#![allow(non_snake_case)]
+
+use util::{ParseError, Solution};
+
+pub struct DayXX;
+
+impl Solution for DayXX {
+    type Input = Problem;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        Problem::parse(data)
+    }
+
+    fn part1(problem: &Self::Input) -> i32 {
+        problem.solve()
+    }
+
+    fn part2(problem: &Self::Input) -> i32 {
+        problem.solve()
+    }
+}
+
+pub struct Problem {}
+
+impl Problem {
+    pub fn parse(_data: &str) -> Result<Self, ParseError> {
+        Ok(Problem {})
+    }
+
+    pub fn solve(&self) -> i32 {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "
+        Something
+    ";
+
+    #[test]
+    fn test_part1_ex1() {
+        let input = DayXX::parse(INPUT).unwrap();
+
+        assert_eq!(DayXX::part1(&input), 0);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = DayXX::parse(INPUT).unwrap();
+
+        assert_eq!(DayXX::part2(&input), 0);
+    }
+}
@@ -0,0 +1,86 @@
+//! Seeds `corpus/dayN` with the example inputs from each day's tests.
+//!
+//! Run from the `fuzz` directory with `cargo run --example seed`. Every
+//! `const NAME: &str = "..."` in a day's sources becomes one corpus file,
+//! with the indentation the tests give it removed.
+
+use std::{fs, path::Path};
+
+fn main() {
+    let mut total = 0;
+
+    for day in 1..=25 {
+        let src = format!("../day{}/src/lib.rs", day);
+        let source = fs::read_to_string(&src).unwrap_or_else(|err| panic!("failed to read '{}': {}", src, err));
+
+        let dir = Path::new("corpus").join(format!("day{}", day));
+        fs::create_dir_all(&dir).unwrap();
+
+        let examples = examples(&source);
+        for (i, (name, example)) in examples.iter().enumerate() {
+            let path = dir.join(format!("{}-{}", name.to_lowercase(), i + 1));
+            fs::write(&path, example).unwrap();
+        }
+
+        println!("day{}: {} examples", day, examples.len());
+        total += examples.len();
+    }
+
+    println!("Seeded {} examples", total);
+}
+
+/// The string constants declared on a line of their own, by name.
+fn examples(source: &str) -> Vec<(String, String)> {
+    let mut examples = Vec::new();
+    let mut lines = source.lines();
+
+    while let Some(line) = lines.next() {
+        let Some(rest) = line.trim().strip_prefix("const ") else {
+            continue;
+        };
+        let Some((name, value)) = rest.split_once(": &str = \"") else {
+            continue;
+        };
+
+        let mut body = vec![value];
+        if let Some(value) = value.strip_suffix("\";") {
+            body = vec![value];
+        } else {
+            for line in lines.by_ref() {
+                match line.strip_suffix("\";") {
+                    Some(last) => {
+                        body.push(last);
+                        break;
+                    }
+                    None => body.push(line),
+                }
+            }
+        }
+
+        examples.push((name.to_string(), unescape(&dedent(&body))));
+    }
+
+    examples
+}
+
+/// Joins the lines with the indentation common to the non-blank ones removed,
+/// and without the blank lines the test constants start and end with.
+fn dedent(lines: &[&str]) -> String {
+    let indent = lines
+        .iter()
+        .filter(|l| !l.trim().is_empty())
+        .map(|l| l.len() - l.trim_start().len())
+        .min()
+        .unwrap_or(0);
+
+    let text: Vec<&str> = lines
+        .iter()
+        .map(|l| if l.trim().is_empty() { "" } else { &l[indent..] })
+        .collect();
+
+    format!("{}\n", text.join("\n").trim_matches('\n'))
+}
+
+fn unescape(s: &str) -> String {
+    s.replace("\\\"", "\"").replace("\\\\", "\\")
+}
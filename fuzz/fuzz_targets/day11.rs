@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use util::Solution;
+
+fuzz_target!(|data: &str| {
+    let _ = day11::Day11::parse(data);
+});
@@ -0,0 +1,79 @@
+//! Programs for the 3-bit computer.
+
+use rand::Rng;
+
+/// Day 17: a program that outputs the last three bits of a mix of `a` and
+/// the bits above them, drops those three bits and loops until `a` is zero:
+///
+/// ```text
+/// 2,4  1,P  7,5  1,Q  4,R  5,5  0,3  3,0
+/// ```
+///
+/// `P` and `Q` are picked so that some value of register A makes the
+/// program output itself, and register A starts out at a random value.
+pub fn program(rng: &mut impl Rng) -> String {
+    loop {
+        let (p, q, r) = (
+            rng.gen_range(0..8),
+            rng.gen_range(0..8),
+            rng.gen_range(0..8),
+        );
+        let program = [2, 4, 1, p, 7, 5, 1, q, 4, r, 5, 5, 0, 3, 3, 0];
+
+        if quine(&program, p, q, program.len(), 0).is_some() {
+            let numbers: Vec<_> = program.iter().map(|n| n.to_string()).collect();
+            return format!(
+                "Register A: {}\nRegister B: 0\nRegister C: 0\n\nProgram: {}\n",
+                rng.gen_range(1..1u64 << 48),
+                numbers.join(",")
+            );
+        }
+    }
+}
+
+/// The first output of the program for register A being `a`.
+fn output(a: u64, p: u64, q: u64) -> u64 {
+    let b = (a & 7) ^ p;
+    let c = a >> b;
+    (b ^ q ^ c) & 7
+}
+
+/// The smallest value of register A, with `a` in its upper bits, for which
+/// the program outputs the last `len` numbers of `program`.
+fn quine(program: &[u64], p: u64, q: u64, len: usize, a: u64) -> Option<u64> {
+    if len == 0 {
+        return Some(a);
+    }
+
+    (0..8)
+        .map(|i| (a << 3) | i)
+        .filter(|&next| next != 0 && output(next, p, q) == program[len - 1])
+        .find_map(|next| quine(program, p, q, len - 1, next))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::rng;
+
+    #[test]
+    fn test_quine() {
+        let input = program(&mut rng(4));
+        let (_, numbers) = input.split_once("Program: ").unwrap();
+        let program: Vec<u64> = numbers
+            .trim()
+            .split(',')
+            .map(|n| n.parse().unwrap())
+            .collect();
+        let (p, q) = (program[3], program[7]);
+
+        let mut a = quine(&program, p, q, program.len(), 0).unwrap();
+        let mut outputs = Vec::new();
+        while a != 0 {
+            outputs.push(output(a, p, q));
+            a >>= 3;
+        }
+        assert_eq!(outputs, program);
+    }
+}
@@ -0,0 +1,233 @@
+//! Inputs that are maps, one row per line.
+
+use std::collections::HashSet;
+
+use rand::{seq::SliceRandom, Rng};
+use util::{
+    geom::{Direction, Point},
+    Grid, Pos,
+};
+
+/// Day 4: a `width` by `height` word search of the letters X, M, A and S.
+pub fn word_search(rng: &mut impl Rng, width: usize, height: usize) -> String {
+    let grid = Grid::new(width, height, ()).map(|_| *['X', 'M', 'A', 'S'].choose(rng).unwrap());
+
+    grid.to_string()
+}
+
+/// Day 6: a `width` by `height` lab with a guard facing up, who walks out
+/// of it before long rather than going around in circles.
+pub fn lab(rng: &mut impl Rng, width: usize, height: usize) -> String {
+    loop {
+        let mut grid =
+            Grid::new(width, height, ()).map(|_| if rng.gen_bool(0.06) { '#' } else { '.' });
+        let guard = (rng.gen_range(0..width), rng.gen_range(0..height));
+        grid[guard] = '^';
+
+        if leaves(&grid, guard) {
+            return grid.to_string();
+        }
+    }
+}
+
+/// Whether the guard starting at `guard` walks off the map.
+fn leaves(grid: &Grid<char>, guard: Pos) -> bool {
+    let mut state = (Point::from(guard), Direction::North);
+    let mut seen = HashSet::new();
+
+    while seen.insert(state) {
+        let (pos, direction) = state;
+        let Some(next) = pos.step(direction).to_pos() else {
+            return true;
+        };
+        state = match grid.get(next) {
+            None => return true,
+            Some('#') => (pos, direction.turn_right()),
+            Some(_) => (pos.step(direction), direction),
+        };
+    }
+
+    false
+}
+
+/// Day 8: a `width` by `height` roof with `frequencies` kinds of antennas,
+/// two to five of each.
+pub fn antennas(rng: &mut impl Rng, width: usize, height: usize, frequencies: usize) -> String {
+    const FREQUENCIES: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+    let mut grid = Grid::new(width, height, '.');
+    let mut positions: Vec<_> = grid.positions().collect();
+    positions.shuffle(rng);
+
+    let mut positions = positions.into_iter();
+    for &frequency in FREQUENCIES.choose_multiple(rng, frequencies) {
+        for pos in positions.by_ref().take(rng.gen_range(2..=5)) {
+            grid[pos] = frequency as char;
+        }
+    }
+
+    grid.to_string()
+}
+
+/// Day 10: a `width` by `height` topographic map of rolling hills, where
+/// each height is at most one away from the one above or to the left, with
+/// hiking trails running all over them.
+pub fn topographic_map(rng: &mut impl Rng, width: usize, height: usize) -> String {
+    let mut heights = Grid::new(width, height, 0i32);
+
+    for pos in heights.positions().collect::<Vec<_>>() {
+        let (x, y) = pos;
+        let base = match (x, y) {
+            (0, 0) => rng.gen_range(0..10),
+            (0, _) => heights[(x, y - 1)],
+            (_, 0) => heights[(x - 1, y)],
+            _ if rng.gen() => heights[(x, y - 1)],
+            _ => heights[(x - 1, y)],
+        };
+        heights[pos] = (base + rng.gen_range(-1..=1)).clamp(0, 9);
+    }
+
+    // Hills this gentle rarely climb all the way from 0 to 9, so the trails
+    // are laid over them. A later trail may cut an earlier one short.
+    for _ in 0..width * height / 25 {
+        let mut trail = vec![(rng.gen_range(0..width), rng.gen_range(0..height))];
+        while trail.len() < 10 {
+            let last = *trail.last().unwrap();
+            let next: Vec<_> = heights
+                .neighbours(last)
+                .filter(|p| !trail.contains(p))
+                .collect();
+            match next.choose(rng) {
+                Some(&pos) => trail.push(pos),
+                None => break,
+            }
+        }
+        for (h, &pos) in trail.iter().enumerate() {
+            heights[pos] = h as i32;
+        }
+    }
+
+    heights
+        .map(|&h| char::from_digit(h as u32, 10).unwrap())
+        .to_string()
+}
+
+/// Day 12: a `width` by `height` garden of plots of a dozen kinds of plant,
+/// where neighbouring plots tend to grow the same kind.
+pub fn garden(rng: &mut impl Rng, width: usize, height: usize) -> String {
+    let mut grid = Grid::new(width, height, 'A');
+
+    for pos in grid.positions().collect::<Vec<_>>() {
+        let (x, y) = pos;
+        grid[pos] = match rng.gen_range(0..10) {
+            0..4 if x > 0 => grid[(x - 1, y)],
+            4..8 if y > 0 => grid[(x, y - 1)],
+            _ => rng.gen_range('A'..='L'),
+        };
+    }
+
+    grid.to_string()
+}
+
+/// Day 15: a `width` by `height` warehouse full of boxes, walled in, with
+/// the robot in the middle, and `moves` moves for it in lines of 70.
+pub fn warehouse(rng: &mut impl Rng, width: usize, height: usize, moves: usize) -> String {
+    let (width, height) = (width.max(3), height.max(3));
+    let mut grid = Grid::new(width, height, '#');
+
+    for (x, y) in grid.positions().collect::<Vec<_>>() {
+        if x > 0 && y > 0 && x < width - 1 && y < height - 1 {
+            grid[(x, y)] = match rng.gen_range(0..20) {
+                0..2 => '#',
+                2..10 => 'O',
+                _ => '.',
+            };
+        }
+    }
+    grid[(width / 2, height / 2)] = '@';
+
+    let moves: Vec<u8> = (0..moves).map(|_| *b"^>v<".choose(rng).unwrap()).collect();
+    let moves: Vec<_> = moves
+        .chunks(70)
+        .map(|line| String::from_utf8_lossy(line))
+        .collect();
+
+    format!("{}\n{}\n", grid, moves.join("\n"))
+}
+
+/// Day 18: bytes falling on a `size` by `size` memory space, until every
+/// position but the start and the exit is corrupted. The exit can still be
+/// reached after the first `open` bytes.
+pub fn byte_drops(rng: &mut impl Rng, size: usize, open: usize) -> String {
+    let size = size.max(2);
+
+    // A random route across, which the first bytes stay off.
+    let mut route = vec![(0, 0)];
+    let (mut x, mut y) = (0, 0);
+    while (x, y) != (size - 1, size - 1) {
+        if y == size - 1 || (x < size - 1 && rng.gen()) {
+            x += 1;
+        } else {
+            y += 1;
+        }
+        route.push((x, y));
+    }
+
+    let (mut first, mut rest): (Vec<_>, Vec<_>) = (0..size)
+        .flat_map(|y| (0..size).map(move |x| (x, y)))
+        .filter(|&p| p != (0, 0) && p != (size - 1, size - 1))
+        .partition(|p| !route.contains(p));
+    first.shuffle(rng);
+    rest.extend(first.drain(open.min(first.len())..));
+    rest.shuffle(rng);
+
+    first
+        .into_iter()
+        .chain(rest)
+        .map(|(x, y)| format!("{},{}\n", x, y))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng;
+
+    #[test]
+    fn test_byte_drops() {
+        let drops = byte_drops(&mut rng(3), 7, 30);
+        let bytes: Vec<Pos> = drops
+            .lines()
+            .map(|l| {
+                let (x, y) = l.split_once(',').unwrap();
+                (x.parse().unwrap(), y.parse().unwrap())
+            })
+            .collect();
+
+        assert_eq!(bytes.len(), 7 * 7 - 2);
+        assert!(!bytes.contains(&(0, 0)) && !bytes.contains(&(6, 6)));
+
+        let corrupted: HashSet<_> = bytes[..30].iter().collect();
+        let space = Grid::new(7, 7, ());
+        let search = util::search::bfs(
+            (0, 0),
+            |&p| {
+                space
+                    .neighbours(p)
+                    .filter(|p| !corrupted.contains(p))
+                    .collect::<Vec<_>>()
+            },
+            |&p| p == (6, 6),
+        );
+        assert!(search.goal.is_some());
+    }
+
+    #[test]
+    fn test_lab() {
+        let lab = Grid::parse(&lab(&mut rng(5), 12, 8)).unwrap();
+        let guard = lab.find(&'^').unwrap();
+
+        assert_eq!((lab.width(), lab.height()), (12, 8));
+        assert!(leaves(&lab, guard));
+    }
+}
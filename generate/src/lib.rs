@@ -0,0 +1,228 @@
+//! Random puzzle inputs, for running the solutions on inputs of any size.
+//!
+//! Every input is valid for its day: it parses, and it has the properties
+//! the puzzle promises, such as a maze whose end can be reached or a guard
+//! who eventually leaves the lab. The same seed always gives the same input.
+
+use rand::{rngs::StdRng, SeedableRng};
+
+pub mod computer;
+pub mod grids;
+pub mod mazes;
+pub mod networks;
+pub mod numbers;
+pub mod text;
+
+/// A day's input generator, scaled by any number of sizes.
+pub struct Generator {
+    pub day: u8,
+    pub sizes: &'static [Size],
+    /// Makes an input from one value for each of the sizes, in order.
+    pub generate: fn(&mut StdRng, &[usize]) -> String,
+}
+
+/// One of the numbers an input is scaled by.
+pub struct Size {
+    /// What it counts, e.g. "lines" or "grid side".
+    pub name: &'static str,
+    /// What it is in the real input, more or less.
+    pub default: usize,
+}
+
+impl Generator {
+    /// An input with the first of the sizes set to `sizes`, and the rest
+    /// left at their defaults. Fails if there are more values than sizes.
+    pub fn generate(&self, rng: &mut StdRng, sizes: &[usize]) -> Result<String, String> {
+        if sizes.len() > self.sizes.len() {
+            return Err(format!(
+                "day {} takes {} size{}, not {}",
+                self.day,
+                self.sizes.len(),
+                if self.sizes.len() == 1 { "" } else { "s" },
+                sizes.len()
+            ));
+        }
+
+        let mut values: Vec<usize> = self.sizes.iter().map(|s| s.default).collect();
+        values[..sizes.len()].copy_from_slice(sizes);
+
+        Ok((self.generate)(rng, &values))
+    }
+}
+
+pub const GENERATORS: &[Generator] = &[
+    Generator {
+        day: 1,
+        sizes: &[Size { name: "lines", default: 1000 }],
+        generate: |r, n| numbers::location_lists(r, n[0]),
+    },
+    Generator {
+        day: 2,
+        sizes: &[Size { name: "reports", default: 1000 }],
+        generate: |r, n| numbers::reports(r, n[0]),
+    },
+    Generator {
+        day: 3,
+        sizes: &[Size { name: "instructions", default: 800 }],
+        generate: |r, n| text::memory(r, n[0]),
+    },
+    Generator {
+        day: 4,
+        sizes: &[Size { name: "grid side", default: 140 }],
+        generate: |r, n| grids::word_search(r, n[0], n[0]),
+    },
+    Generator {
+        day: 5,
+        sizes: &[Size { name: "updates", default: 200 }],
+        generate: |r, n| text::print_queue(r, 49, n[0]),
+    },
+    Generator {
+        day: 6,
+        sizes: &[Size { name: "grid side", default: 130 }],
+        generate: |r, n| grids::lab(r, n[0], n[0]),
+    },
+    Generator {
+        day: 7,
+        sizes: &[Size { name: "equations", default: 850 }],
+        generate: |r, n| numbers::equations(r, n[0]),
+    },
+    Generator {
+        day: 8,
+        sizes: &[Size { name: "grid side", default: 50 }],
+        generate: |r, n| grids::antennas(r, n[0], n[0], n[0] / 8 + 1),
+    },
+    Generator {
+        day: 9,
+        sizes: &[Size { name: "disk map digits", default: 20000 }],
+        generate: |r, n| numbers::disk_map(r, n[0]),
+    },
+    Generator {
+        day: 10,
+        sizes: &[Size { name: "grid side", default: 50 }],
+        generate: |r, n| grids::topographic_map(r, n[0], n[0]),
+    },
+    Generator {
+        day: 11,
+        sizes: &[Size { name: "stones", default: 8 }],
+        generate: |r, n| numbers::stones(r, n[0]),
+    },
+    Generator {
+        day: 12,
+        sizes: &[Size { name: "grid side", default: 140 }],
+        generate: |r, n| grids::garden(r, n[0], n[0]),
+    },
+    Generator {
+        day: 13,
+        sizes: &[Size { name: "machines", default: 320 }],
+        generate: |r, n| numbers::claw_machines(r, n[0]),
+    },
+    Generator {
+        day: 14,
+        sizes: &[Size { name: "robots", default: 500 }],
+        generate: |r, n| numbers::robots(r, n[0]),
+    },
+    Generator {
+        day: 15,
+        sizes: &[Size { name: "grid side", default: 50 }],
+        generate: |r, n| grids::warehouse(r, n[0], n[0], 400 * n[0]),
+    },
+    Generator {
+        day: 16,
+        sizes: &[Size { name: "grid side", default: 141 }],
+        generate: |r, n| mazes::reindeer_maze(r, n[0], n[0], n[0]),
+    },
+    Generator {
+        day: 17,
+        sizes: &[],
+        generate: |r, _| computer::program(r),
+    },
+    Generator {
+        day: 18,
+        sizes: &[
+            Size { name: "grid side", default: 71 },
+            Size { name: "bytes before the exit can be cut off", default: 1024 },
+        ],
+        generate: |r, n| grids::byte_drops(r, n[0], n[1]),
+    },
+    Generator {
+        day: 19,
+        sizes: &[Size { name: "designs", default: 400 }],
+        generate: |r, n| text::towels(r, 450, n[0]),
+    },
+    Generator {
+        day: 20,
+        sizes: &[Size { name: "grid side", default: 141 }],
+        generate: |r, n| mazes::racetrack(r, n[0], n[0]),
+    },
+    Generator {
+        day: 21,
+        sizes: &[Size { name: "codes", default: 5 }],
+        generate: |r, n| numbers::door_codes(r, n[0]),
+    },
+    Generator {
+        day: 22,
+        sizes: &[Size { name: "buyers", default: 2000 }],
+        generate: |r, n| numbers::secrets(r, n[0]),
+    },
+    Generator {
+        day: 23,
+        sizes: &[Size { name: "computers", default: 520 }],
+        generate: |r, n| networks::lan(r, n[0], 13),
+    },
+    Generator {
+        day: 24,
+        sizes: &[Size { name: "bits", default: 45 }],
+        generate: |r, n| networks::adder(r, n[0], 4),
+    },
+    Generator {
+        day: 25,
+        sizes: &[Size { name: "schematics", default: 500 }],
+        generate: |r, n| text::schematics(r, n[0]),
+    },
+];
+
+pub fn find(day: u8) -> Option<&'static Generator> {
+    GENERATORS.iter().find(|g| g.day == day)
+}
+
+/// The random numbers an input with `seed` is made from.
+pub fn rng(seed: u64) -> StdRng {
+    StdRng::seed_from_u64(seed)
+}
+
+/// A random input for `day` of the given `sizes`, as with
+/// [`Generator::generate`], if there is a generator for it.
+pub fn generate(day: u8, sizes: &[usize], seed: u64) -> Option<Result<String, String>> {
+    find(day).map(|g| g.generate(&mut rng(seed), sizes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seed() {
+        for g in GENERATORS {
+            let sizes: Vec<usize> = g.sizes.iter().map(|s| s.default.min(20)).collect();
+            let input = g.generate(&mut rng(7), &sizes).unwrap();
+
+            assert_eq!(input, g.generate(&mut rng(7), &sizes).unwrap(), "day {}", g.day);
+            assert!(input.ends_with('\n'), "day {}", g.day);
+        }
+
+        assert_ne!(generate(9, &[100], 1), generate(9, &[100], 2));
+        assert_eq!(generate(26, &[100], 1), None);
+    }
+
+    #[test]
+    fn test_sizes() {
+        // A 7 by 7 space has 47 positions for bytes to fall on.
+        let input = generate(18, &[7], 0).unwrap().unwrap();
+        assert_eq!(input.lines().count(), 47);
+        assert!(input.lines().all(|l| l.split(',').all(|n| n.parse::<u32>().unwrap() < 7)));
+
+        assert_eq!(generate(18, &[], 0), generate(18, &[71, 1024], 0));
+        assert_eq!(generate(17, &[10], 0), Some(Err("day 17 takes 0 sizes, not 1".to_string())));
+        assert_eq!(generate(18, &[7, 10, 1], 0), Some(Err("day 18 takes 2 sizes, not 3".to_string())));
+    }
+}
@@ -0,0 +1,142 @@
+//! Mazes, carved out of solid wall.
+
+use rand::{seq::SliceRandom, Rng};
+use util::{search::Search, Grid, Pos};
+
+/// A perfect maze: the cells at odd positions of a grid of walls, joined
+/// by a spanning tree of passages, so that every cell can be reached from
+/// every other one in exactly one way.
+///
+/// The grid is `width` by `height` rounded down to odd sizes of at least 5,
+/// so that there are always two cells.
+fn carve(rng: &mut impl Rng, width: usize, height: usize) -> Grid<char> {
+    let width = (width.max(5) - 1) / 2 * 2 + 1;
+    let height = (height.max(5) - 1) / 2 * 2 + 1;
+    let mut grid = Grid::new(width, height, '#');
+
+    // A depth first walk, which makes long winding passages.
+    let mut stack = vec![(1, 1)];
+    grid[(1, 1)] = '.';
+    while let Some(&(x, y)) = stack.last() {
+        let mut next: Vec<Pos> = [(0, -2), (2, 0), (0, 2), (-2, 0)]
+            .into_iter()
+            .filter_map(|(dx, dy)| grid.step((x, y), (dx, dy)))
+            .filter(|&p| grid[p] == '#' && p.0 % 2 == 1 && p.1 % 2 == 1)
+            .collect();
+        next.shuffle(rng);
+
+        match next.first() {
+            Some(&(nx, ny)) => {
+                grid[((x + nx) / 2, (y + ny) / 2)] = '.';
+                grid[(nx, ny)] = '.';
+                stack.push((nx, ny));
+            }
+            None => {
+                stack.pop();
+            }
+        }
+    }
+
+    grid
+}
+
+/// Day 16: a `width` by `height` maze with the start in the bottom left
+/// corner and the end in the top right one. `loops` extra walls are broken
+/// through, which makes for more than one way to the end.
+pub fn reindeer_maze(rng: &mut impl Rng, width: usize, height: usize, loops: usize) -> String {
+    let mut grid = carve(rng, width, height);
+
+    // Walls between two cells in the same row or column.
+    let mut inner: Vec<Pos> = grid
+        .positions()
+        .filter(|&(x, y)| {
+            grid[(x, y)] == '#'
+                && x > 0
+                && y > 0
+                && x < grid.width() - 1
+                && y < grid.height() - 1
+                && (x % 2 == 1) != (y % 2 == 1)
+        })
+        .collect();
+    inner.shuffle(rng);
+    for &pos in inner.iter().take(loops) {
+        grid[pos] = '.';
+    }
+
+    let (width, height) = (grid.width(), grid.height());
+    grid[(1, height - 2)] = 'S';
+    grid[(width - 2, 1)] = 'E';
+
+    grid.to_string()
+}
+
+/// Day 20: a `width` by `height` racetrack, a single lane winding from the
+/// start to the end with walls on both sides all the way.
+pub fn racetrack(rng: &mut impl Rng, width: usize, height: usize) -> String {
+    let maze = carve(rng, width, height);
+
+    // The track is the longest way through the maze: from the cell furthest
+    // from (1, 1) to the cell furthest from that one. Any other passage is
+    // walled up again.
+    let explore = |from: Pos| {
+        util::search::bfs(
+            from,
+            |&pos| {
+                maze.neighbours(pos)
+                    .filter(|&p| maze[p] == '.')
+                    .collect::<Vec<_>>()
+            },
+            |_| false,
+        )
+    };
+    let furthest = |search: &Search<Pos>| {
+        let mut cells: Vec<_> = search.distances.iter().collect();
+        cells.sort();
+        *cells.into_iter().max_by_key(|(_, &d)| d).unwrap().0
+    };
+
+    let start = furthest(&explore((1, 1)));
+    let search = explore(start);
+    let end = furthest(&search);
+
+    let mut grid = Grid::new(maze.width(), maze.height(), '#');
+    for pos in search.path(&end).unwrap() {
+        grid[pos] = '.';
+    }
+    grid[start] = 'S';
+    grid[end] = 'E';
+
+    grid.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng;
+
+    #[test]
+    fn test_racetrack() {
+        let track = racetrack(&mut rng(1), 21, 15);
+        let grid = Grid::parse(&track).unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (21, 15));
+        let open = |p: &Pos| grid[*p] != '#';
+        let lane: Vec<Pos> = grid.positions().filter(open).collect();
+        for &pos in &lane {
+            let ways = grid.neighbours(pos).filter(open).count();
+            let ends = if matches!(grid[pos], 'S' | 'E') { 1 } else { 2 };
+            assert_eq!(ways, ends, "{:?} in\n{}", pos, track);
+        }
+    }
+
+    #[test]
+    fn test_reindeer_maze() {
+        let maze = Grid::parse(&reindeer_maze(&mut rng(1), 10, 10, 0)).unwrap();
+
+        assert_eq!((maze.width(), maze.height()), (9, 9));
+        assert_eq!(maze[(1, 7)], 'S');
+        assert_eq!(maze[(7, 1)], 'E');
+        // A tree of 16 cells has 15 passages between them.
+        assert_eq!(maze.iter().filter(|(_, &c)| c != '#').count(), 16 + 15);
+    }
+}
@@ -0,0 +1,180 @@
+//! Inputs that describe graphs: computer networks and logic circuits.
+
+use std::{collections::BTreeSet, fmt::Write};
+
+use rand::{seq::SliceRandom, Rng};
+
+/// Random distinct names of three lowercase letters, none starting with
+/// x, y or z.
+fn names(rng: &mut impl Rng, count: usize) -> Vec<String> {
+    let mut names: Vec<String> = ('a'..='w')
+        .flat_map(|a| {
+            ('a'..='z').flat_map(move |b| ('a'..='z').map(move |c| format!("{}{}{}", a, b, c)))
+        })
+        .collect();
+    names.shuffle(rng);
+    names.truncate(count);
+
+    names
+}
+
+/// Day 23: a LAN of `computers` computers with two letter names, each
+/// linked to a few others, and one LAN party of `party` computers that are
+/// all linked to each other.
+pub fn lan(rng: &mut impl Rng, computers: usize, party: usize) -> String {
+    let mut names: Vec<String> = ('a'..='z')
+        .flat_map(|a| ('a'..='z').map(move |b| format!("{}{}", a, b)))
+        .collect();
+    names.shuffle(rng);
+    names.truncate(computers.max(2));
+
+    // Ordered, so that the same seed gives the same links in the same order.
+    let mut links = BTreeSet::new();
+    let mut link = |a: usize, b: usize| {
+        if a != b {
+            links.insert((a.min(b), a.max(b)));
+        }
+    };
+
+    let party = party.min(names.len());
+    for a in 0..party {
+        for b in a + 1..party {
+            link(a, b);
+        }
+    }
+    for a in 0..names.len() {
+        for _ in 0..rng.gen_range(1..=3) {
+            link(a, rng.gen_range(0..names.len()));
+        }
+    }
+
+    let mut links: Vec<_> = links.into_iter().collect();
+    links.shuffle(rng);
+
+    let mut out = String::new();
+    for (a, b) in links {
+        let (a, b) = if rng.gen() { (a, b) } else { (b, a) };
+        let _ = writeln!(out, "{}-{}", names[a], names[b]);
+    }
+
+    out
+}
+
+/// Day 24: the initial values of two `bits` bit numbers and a ripple carry
+/// adder for them, with the outputs of `swaps` pairs of gates swapped.
+///
+/// Each swap is between two gates of the same bit, neither of which feeds
+/// the other, so the circuit never loops back on itself.
+pub fn adder(rng: &mut impl Rng, bits: usize, swaps: usize) -> String {
+    let bits = bits.clamp(2, 99);
+
+    let mut out = String::new();
+    for input in ["x", "y"] {
+        for i in 0..bits {
+            let _ = writeln!(out, "{}{:02}: {}", input, i, rng.gen_range(0..2));
+        }
+    }
+    out.push('\n');
+
+    // Five gates per bit, except for the first which is a half adder:
+    //
+    //   x XOR y -> sum      sum XOR carry -> z
+    //   x AND y -> both     sum AND carry -> pass     both OR pass -> carry
+    let mut wires = names(rng, 5 * bits).into_iter();
+    let mut gates: Vec<Vec<(String, &str, String, String)>> = Vec::new();
+
+    let carry = wires.next().unwrap();
+    gates.push(vec![
+        (
+            "x00".to_string(),
+            "XOR",
+            "y00".to_string(),
+            "z00".to_string(),
+        ),
+        ("x00".to_string(), "AND", "y00".to_string(), carry.clone()),
+    ]);
+    let mut carry = carry;
+    for i in 1..bits {
+        let (x, y, z) = (
+            format!("x{:02}", i),
+            format!("y{:02}", i),
+            format!("z{:02}", i),
+        );
+        let [sum, both, pass, next] = [(); 4].map(|_| wires.next().unwrap());
+        let next = if i == bits - 1 {
+            format!("z{:02}", bits)
+        } else {
+            next
+        };
+
+        gates.push(vec![
+            (x.clone(), "XOR", y.clone(), sum.clone()),
+            (sum.clone(), "XOR", carry.clone(), z),
+            (x, "AND", y, both.clone()),
+            (sum, "AND", carry, pass.clone()),
+            (both, "OR", pass, next.clone()),
+        ]);
+        carry = next;
+    }
+
+    let mut bits: Vec<usize> = (1..gates.len()).collect();
+    bits.shuffle(rng);
+    for &i in bits.iter().take(swaps) {
+        let &(a, b) = [(0, 2), (1, 2), (1, 3), (1, 4)].choose(rng).unwrap();
+        let wire = gates[i][a].3.clone();
+        gates[i][a].3 = gates[i][b].3.clone();
+        gates[i][b].3 = wire;
+    }
+
+    let mut gates: Vec<_> = gates.into_iter().flatten().collect();
+    gates.shuffle(rng);
+    for (a, op, b, wire) in gates {
+        let (a, b) = if rng.gen() { (a, b) } else { (b, a) };
+        let _ = writeln!(out, "{} {} {} -> {}", a, op, b, wire);
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use day24::Day24;
+    use util::Solution;
+
+    use super::*;
+    use crate::rng;
+
+    /// The number made of the initial values of the wires named `prefix`.
+    fn number(input: &str, prefix: char) -> usize {
+        input
+            .lines()
+            .filter_map(|l| l.strip_prefix(prefix))
+            .filter_map(|l| l.split_once(": "))
+            .map(|(i, v)| v.parse::<usize>().unwrap() << i.parse::<usize>().unwrap())
+            .sum()
+    }
+
+    #[test]
+    fn test_adder() {
+        for seed in 0..5 {
+            let input = adder(&mut rng(seed), 12, 0);
+            let sum = number(&input, 'x') + number(&input, 'y');
+
+            assert_eq!(Day24::part1(&Day24::parse(&input).unwrap()), sum);
+        }
+    }
+
+    #[test]
+    fn test_lan() {
+        let input = lan(&mut rng(2), 30, 6);
+        let links: HashSet<(&str, &str)> =
+            input.lines().filter_map(|l| l.split_once('-')).collect();
+
+        assert!(links.len() >= 30 + 15);
+        assert!(links
+            .iter()
+            .all(|(a, b)| a.len() == 2 && b.len() == 2 && a != b));
+    }
+}
@@ -0,0 +1,212 @@
+//! Inputs that are lists of numbers, one record per line.
+
+use std::fmt::Write;
+
+use rand::{seq::SliceRandom, Rng};
+
+/// Day 1: two columns of `lines` five digit location ids, with some ids
+/// showing up more than once.
+pub fn location_lists(rng: &mut impl Rng, lines: usize) -> String {
+    let ids: Vec<u32> = (0..lines.max(1))
+        .map(|_| rng.gen_range(10000..100000))
+        .collect();
+
+    let mut out = String::new();
+    for _ in 0..lines {
+        let left = if rng.gen_bool(0.1) {
+            *ids.choose(rng).unwrap()
+        } else {
+            rng.gen_range(10000..100000)
+        };
+        let _ = writeln!(out, "{}   {}", left, ids.choose(rng).unwrap());
+    }
+
+    out
+}
+
+/// Day 2: `count` reports of 5 to 8 levels. Most of them change steadily
+/// in one direction, and some of those have a single bad level.
+pub fn reports(rng: &mut impl Rng, count: usize) -> String {
+    let mut out = String::new();
+
+    for _ in 0..count {
+        let len = rng.gen_range(5..=8);
+        let mut levels: Vec<i32> = if rng.gen_bool(0.8) {
+            let sign = if rng.gen() { 1 } else { -1 };
+            let mut level = rng.gen_range(20..70);
+            (0..len)
+                .map(|_| {
+                    level += sign * rng.gen_range(1..=3);
+                    level
+                })
+                .collect()
+        } else {
+            (0..len).map(|_| rng.gen_range(1..100)).collect()
+        };
+
+        if rng.gen_bool(0.3) {
+            let i = rng.gen_range(0..len);
+            levels[i] = rng.gen_range(1..100);
+        }
+
+        let levels: Vec<_> = levels.iter().map(|l| l.to_string()).collect();
+        let _ = writeln!(out, "{}", levels.join(" "));
+    }
+
+    out
+}
+
+/// Day 7: `count` equations of 2 to 12 numbers, about half of which can be
+/// made true. No way of combining the numbers overflows an `i64`.
+pub fn equations(rng: &mut impl Rng, count: usize) -> String {
+    let mut out = String::new();
+
+    for _ in 0..count {
+        // Every result is at most the numbers concatenated, so keeping that
+        // under 18 digits keeps every result in range.
+        let mut numbers: Vec<u64> = Vec::new();
+        let mut digits = 0;
+        let len = rng.gen_range(2..=12);
+        while numbers.len() < len {
+            let n: u64 = rng.gen_range(1..1000);
+            digits += n.to_string().len();
+            if digits > 17 {
+                break;
+            }
+            numbers.push(n);
+        }
+
+        let answer = if rng.gen() {
+            numbers[1..]
+                .iter()
+                .fold(numbers[0], |acc, &n| match rng.gen_range(0..3) {
+                    0 => acc + n,
+                    1 => acc * n,
+                    _ => format!("{}{}", acc, n).parse().unwrap(),
+                })
+        } else {
+            numbers.iter().sum::<u64>() * rng.gen_range(1..100) + rng.gen_range(0..100)
+        };
+
+        let numbers: Vec<_> = numbers.iter().map(|n| n.to_string()).collect();
+        let _ = writeln!(out, "{}: {}", answer, numbers.join(" "));
+    }
+
+    out
+}
+
+/// Day 9: a disk map of `len` digits, alternating files of 1 to 9 blocks
+/// and free spans of 0 to 9.
+pub fn disk_map(rng: &mut impl Rng, len: usize) -> String {
+    let mut out: String = (0..len)
+        .map(|i| {
+            let digit = if i % 2 == 0 {
+                rng.gen_range(1..10)
+            } else {
+                rng.gen_range(0..10)
+            };
+            char::from_digit(digit, 10).unwrap()
+        })
+        .collect();
+
+    out.push('\n');
+    out
+}
+
+/// Day 11: `count` stones engraved with numbers of up to 7 digits.
+pub fn stones(rng: &mut impl Rng, count: usize) -> String {
+    let stones: Vec<_> = (0..count)
+        .map(|_| {
+            let digits = rng.gen_range(1..=7);
+            rng.gen_range(0..10u64.pow(digits)).to_string()
+        })
+        .collect();
+
+    stones.join(" ") + "\n"
+}
+
+/// Day 13: `count` claw machines, about half of which can be won within a
+/// hundred presses of each button.
+pub fn claw_machines(rng: &mut impl Rng, count: usize) -> String {
+    let mut machines = Vec::new();
+
+    for _ in 0..count {
+        let a = (rng.gen_range(10..100), rng.gen_range(10..100));
+        let b = (rng.gen_range(10..100), rng.gen_range(10..100));
+        let prize = if rng.gen() {
+            let (i, j) = (rng.gen_range(0..=100), rng.gen_range(0..=100));
+            (i * a.0 + j * b.0, i * a.1 + j * b.1)
+        } else {
+            (rng.gen_range(1000..20000), rng.gen_range(1000..20000))
+        };
+
+        machines.push(format!(
+            "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n",
+            a.0, a.1, b.0, b.1, prize.0, prize.1
+        ));
+    }
+
+    machines.join("\n")
+}
+
+/// Day 14: `count` robots in the 101 by 103 bathroom. Some point in their
+/// cycle is the first without two robots on the same tile, and there they
+/// draw a picture framed by a box.
+pub fn robots(rng: &mut impl Rng, count: usize) -> String {
+    const WIDTH: i32 = 101;
+    const HEIGHT: i32 = 103;
+
+    // The frame and a filled triangle inside it, as much of them as there
+    // are robots for, with the rest of the robots scattered around.
+    let (left, top) = (rng.gen_range(0..WIDTH - 31), rng.gen_range(0..HEIGHT - 33));
+    let mut picture = Vec::new();
+    for y in 0..33i32 {
+        for x in 0..31i32 {
+            let border = x == 0 || x == 30 || y == 0 || y == 32;
+            let tree = (2..31).contains(&y) && (x - 15).abs() <= (y - 2) / 2;
+            if border || tree {
+                picture.push((left + x, top + y));
+            }
+        }
+    }
+    picture.truncate(count * 2 / 3);
+
+    let mut tiles: Vec<(i32, i32)> = (0..WIDTH)
+        .flat_map(|x| (0..HEIGHT).map(move |y| (x, y)))
+        .filter(|p| !picture.contains(p))
+        .collect();
+    tiles.shuffle(rng);
+    tiles.truncate(count - picture.len());
+
+    let seconds = rng.gen_range(0..WIDTH * HEIGHT);
+    let mut robots: Vec<_> = picture
+        .into_iter()
+        .chain(tiles)
+        .map(|(x, y)| {
+            let (vx, vy) = (
+                rng.gen_range(-WIDTH + 1..WIDTH),
+                rng.gen_range(-HEIGHT + 1..HEIGHT),
+            );
+            let px = (x - vx * seconds).rem_euclid(WIDTH);
+            let py = (y - vy * seconds).rem_euclid(HEIGHT);
+            format!("p={},{} v={},{}\n", px, py, vx, vy)
+        })
+        .collect();
+    robots.shuffle(rng);
+
+    robots.concat()
+}
+
+/// Day 21: `count` door codes of three digits and an 'A'.
+pub fn door_codes(rng: &mut impl Rng, count: usize) -> String {
+    (0..count)
+        .map(|_| format!("{:03}A\n", rng.gen_range(1..1000)))
+        .collect()
+}
+
+/// Day 22: the initial secret numbers of `count` buyers.
+pub fn secrets(rng: &mut impl Rng, count: usize) -> String {
+    (0..count)
+        .map(|_| format!("{}\n", rng.gen_range(1..1 << 24)))
+        .collect()
+}
@@ -0,0 +1,139 @@
+//! Inputs made of words and symbols rather than plain numbers or maps.
+
+use std::{collections::HashSet, fmt::Write};
+
+use rand::{seq::SliceRandom, Rng};
+
+/// Day 3: corrupted memory with `count` instructions, most of them `mul`s
+/// and some of them broken, among runs of junk characters.
+pub fn memory(rng: &mut impl Rng, count: usize) -> String {
+    const JUNK: &[u8] = b"!@#$%^&*()[]{}<>,;:'?/+- _who()select()from()when()";
+
+    let mut out = String::new();
+    for _ in 0..count {
+        for _ in 0..rng.gen_range(0..12) {
+            out.push(*JUNK.choose(rng).unwrap() as char);
+        }
+
+        let (a, b) = (rng.gen_range(1..1000), rng.gen_range(1..1000));
+        let _ = match rng.gen_range(0..10) {
+            0 => write!(out, "do()"),
+            1 => write!(out, "don't()"),
+            2 => write!(out, "mul({},{}]", a, b),
+            3 => write!(out, "mul({}, {})", a, b),
+            4 => write!(out, "mul({},{}", a, b),
+            _ => write!(out, "mul({},{})", a, b),
+        };
+    }
+
+    out.push('\n');
+    out
+}
+
+/// Day 5: rules ordering `pages` two digit page numbers, and `updates`
+/// updates of an odd number of those pages, about half of them in order.
+///
+/// There is a rule for every pair of pages, so each update has exactly one
+/// correct order.
+pub fn print_queue(rng: &mut impl Rng, pages: usize, updates: usize) -> String {
+    let mut order: Vec<u32> = (10..100).collect();
+    order.shuffle(rng);
+    order.truncate(pages.clamp(1, 90));
+
+    let mut rules = Vec::new();
+    for (i, a) in order.iter().enumerate() {
+        for b in &order[i + 1..] {
+            rules.push(format!("{}|{}\n", a, b));
+        }
+    }
+    rules.shuffle(rng);
+
+    let mut out = rules.concat();
+    out.push('\n');
+    let most = (order.len().min(23) - 1) / 2;
+    for _ in 0..updates {
+        let len = rng.gen_range(most.min(2)..=most) * 2 + 1;
+        let mut update: Vec<_> = order.choose_multiple(rng, len).copied().collect();
+        if rng.gen() {
+            update.sort_by_key(|page| order.iter().position(|p| p == page));
+        }
+
+        let update: Vec<_> = update.iter().map(|p| p.to_string()).collect();
+        let _ = writeln!(out, "{}", update.join(","));
+    }
+
+    out
+}
+
+/// Day 19: `patterns` towel patterns of 1 to 8 stripes, and `designs`
+/// designs of up to 60 stripes. About half the designs are made from the
+/// patterns, the rest are random and can only be made by chance.
+pub fn towels(rng: &mut impl Rng, patterns: usize, designs: usize) -> String {
+    const COLORS: &[u8] = b"wubrg";
+
+    let stripes = |rng: &mut _, len| -> String {
+        (0..len)
+            .map(|_| *COLORS.choose(rng).unwrap() as char)
+            .collect()
+    };
+
+    // Short patterns run out quickly, so there may be fewer than asked for.
+    let mut seen = HashSet::new();
+    let mut towels = Vec::new();
+    for _ in 0..patterns * 2 {
+        let len = rng.gen_range(1..=8);
+        let pattern = stripes(rng, len);
+        if seen.insert(pattern.clone()) {
+            towels.push(pattern);
+        }
+        if towels.len() == patterns {
+            break;
+        }
+    }
+
+    let mut out = towels.join(", ") + "\n\n";
+    for _ in 0..designs {
+        let design = if rng.gen() {
+            let mut design = String::new();
+            while design.len() < 20 || (design.len() < 50 && rng.gen_bool(0.8)) {
+                design += towels.choose(rng).unwrap();
+            }
+            design
+        } else {
+            let len = rng.gen_range(20..=60);
+            stripes(rng, len)
+        };
+        let _ = writeln!(out, "{}", design);
+    }
+
+    out
+}
+
+/// Day 25: `count` schematics of five pin locks and keys.
+pub fn schematics(rng: &mut impl Rng, count: usize) -> String {
+    let mut schematics = Vec::new();
+
+    for _ in 0..count {
+        let heights: Vec<usize> = (0..5).map(|_| rng.gen_range(0..=5)).collect();
+        let lock = rng.gen();
+
+        let rows: Vec<String> = (0..7)
+            .map(|y| {
+                heights
+                    .iter()
+                    .map(|&h| {
+                        let filled = if lock { y <= h } else { 6 - y <= h };
+                        if filled {
+                            '#'
+                        } else {
+                            '.'
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+        schematics.push(rows.join("\n") + "\n");
+    }
+
+    schematics.join("\n")
+}
@@ -0,0 +1,188 @@
+//! Differential testing: running a solution and a slow but obviously right
+//! reference on the same random inputs, and boiling any input they disagree
+//! on down to as little as still shows the disagreement.
+
+use std::{
+    fmt::{self, Debug, Display},
+    panic::{self, AssertUnwindSafe},
+};
+
+/// What a solver came up with, or what it panicked with.
+pub type Outcome<T> = Result<T, String>;
+
+/// An input on which the solution and the reference give different answers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Disagreement<T> {
+    pub input: String,
+    pub solution: Outcome<T>,
+    pub reference: Outcome<T>,
+    /// How many valid inputs were tried, up to the one this was shrunk from.
+    pub tried: usize,
+}
+
+impl<T: Debug> Display for Disagreement<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "the solution and the reference disagree on input {}:",
+            self.tried
+        )?;
+        writeln!(f, "{}", self.input.trim_end())?;
+        writeln!(f, "solution:  {:?}", self.solution)?;
+        write!(f, "reference: {:?}", self.reference)
+    }
+}
+
+/// Compares `solution` with `reference` on `inputs`, skipping any that are
+/// not `valid`, i.e. that break a promise the puzzle makes about its input.
+///
+/// The first disagreement is shrunk by dropping lines, and then items on a
+/// line, for as long as what is left is valid and still disagreed on.
+pub fn compare<T: PartialEq>(
+    inputs: impl IntoIterator<Item = String>,
+    valid: impl Fn(&str) -> bool,
+    solution: impl Fn(&str) -> T,
+    reference: impl Fn(&str) -> T,
+) -> Option<Disagreement<T>> {
+    let disagreement = |input: &str| {
+        let solution = run(|| solution(input));
+        let reference = run(|| reference(input));
+        (solution != reference).then_some((solution, reference))
+    };
+
+    let (tried, mut input, mut outcomes) = inputs
+        .into_iter()
+        .filter(|input| valid(input))
+        .enumerate()
+        .find_map(|(i, input)| disagreement(&input).map(|outcomes| (i + 1, input, outcomes)))?;
+
+    'shrink: loop {
+        for smaller in shrink(&input) {
+            if valid(&smaller) {
+                if let Some(found) = disagreement(&smaller) {
+                    (input, outcomes) = (smaller, found);
+                    continue 'shrink;
+                }
+            }
+        }
+        break;
+    }
+
+    Some(Disagreement {
+        input,
+        solution: outcomes.0,
+        reference: outcomes.1,
+        tried,
+    })
+}
+
+/// Runs `f`, catching a panic as its message. The panic hook is left alone,
+/// so the message is still printed as well.
+fn run<T>(f: impl FnOnce() -> T) -> Outcome<T> {
+    panic::catch_unwind(AssertUnwindSafe(f)).map_err(|payload| {
+        if let Some(s) = payload.downcast_ref::<&str>() {
+            s.to_string()
+        } else if let Some(s) = payload.downcast_ref::<String>() {
+            s.clone()
+        } else {
+            "panicked".to_string()
+        }
+    })
+}
+
+/// The inputs that are `input` with one line left out, and then those with
+/// one comma or space separated item left out of a line.
+fn shrink(input: &str) -> Vec<String> {
+    let lines: Vec<&str> = input.lines().collect();
+    let without = |i: usize, line: Option<String>| {
+        let mut lines: Vec<String> = lines.iter().map(|l| l.to_string()).collect();
+        match line {
+            Some(line) => lines[i] = line,
+            None => {
+                lines.remove(i);
+            }
+        }
+        lines.iter().map(|l| format!("{}\n", l)).collect()
+    };
+
+    let mut smaller: Vec<String> = (0..lines.len()).map(|i| without(i, None)).collect();
+    for (i, line) in lines.iter().enumerate() {
+        for separator in [',', ' '] {
+            let items: Vec<&str> = line.split(separator).collect();
+            if items.len() < 2 {
+                continue;
+            }
+            for j in 0..items.len() {
+                let mut items = items.clone();
+                items.remove(j);
+                smaller.push(without(i, Some(items.join(&separator.to_string()))));
+            }
+        }
+    }
+
+    smaller
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Sums the numbers on each line, wrongly once one of them is above 6.
+    fn sums(input: &str) -> Vec<u32> {
+        input
+            .lines()
+            .map(|l| {
+                l.split(',')
+                    .map(|n| n.parse::<u32>().unwrap())
+                    .map(|n| n.min(6))
+                    .sum()
+            })
+            .collect()
+    }
+
+    fn reference(input: &str) -> Vec<u32> {
+        input
+            .lines()
+            .map(|l| l.split(',').map(|n| n.parse::<u32>().unwrap()).sum())
+            .collect()
+    }
+
+    #[test]
+    fn test_compare() {
+        let inputs = ["1,2\n3\n", "4,5\n", "1,2,3\n6,7,8\n", "7\n"].map(String::from);
+        let disagreement = compare(inputs.clone(), |_| true, sums, reference).unwrap();
+
+        assert_eq!(disagreement.input, "8\n");
+        assert_eq!(disagreement.solution, Ok(vec![6]));
+        assert_eq!(disagreement.reference, Ok(vec![8]));
+        assert_eq!(disagreement.tried, 3);
+
+        let valid = |input: &str| !input.contains('8');
+        let disagreement = compare(inputs.clone(), valid, sums, reference).unwrap();
+        assert_eq!(disagreement.input, "7\n");
+
+        let agree = compare(inputs, |_| true, reference, reference);
+        assert_eq!(agree, None);
+    }
+
+    #[test]
+    fn test_panic() {
+        let inputs = ["1\n2\n0\n3\n".to_string()];
+        let numbers =
+            |input: &str| -> Vec<u32> { input.lines().map(|l| l.parse().unwrap()).collect() };
+        let divide = |input: &str| numbers(input).iter().map(|n| 6 / n).sum::<u32>();
+        let checked = |input: &str| {
+            numbers(input)
+                .iter()
+                .filter_map(|n| 6u32.checked_div(*n))
+                .sum()
+        };
+        let disagreement = compare(inputs, |_| true, divide, checked).unwrap();
+
+        assert_eq!(disagreement.input, "0\n");
+        assert!(disagreement
+            .solution
+            .unwrap_err()
+            .contains("divide by zero"));
+    }
+}
@@ -0,0 +1,406 @@
+//! The solutions against slow but obviously right references, on many small
+//! random inputs. Each reference tries every possible answer rather than
+//! relying on the shortcut the solution takes.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::{Debug, Write},
+};
+
+use compare::{compare, Disagreement};
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+use util::Solution;
+
+mod compare;
+
+/// `count` random inputs, from seeds 0 and up.
+fn inputs(count: u64, generate: impl Fn(&mut StdRng) -> String) -> impl Iterator<Item = String> {
+    (0..count).map(move |seed| generate(&mut generate::rng(seed)))
+}
+
+fn solve<S: Solution>(input: &str) -> (S::Answer1, S::Answer2) {
+    let input = S::parse(input).unwrap();
+    (S::part1(&input), S::part2(&input))
+}
+
+fn check<T: Debug>(disagreement: Option<Disagreement<T>>) {
+    if let Some(disagreement) = disagreement {
+        panic!("{}", disagreement);
+    }
+}
+
+/// Every order of `items`.
+fn permutations<T: Clone>(items: &[T]) -> Vec<Vec<T>> {
+    if items.is_empty() {
+        return vec![Vec::new()];
+    }
+
+    let mut result = Vec::new();
+    for i in 0..items.len() {
+        let mut rest = items.to_vec();
+        let first = rest.remove(i);
+        for mut permutation in permutations(&rest) {
+            permutation.insert(0, first.clone());
+            result.push(permutation);
+        }
+    }
+
+    result
+}
+
+/// Rules as `before|after` pairs, and the updates.
+type PrintQueue = (BTreeSet<(u32, u32)>, Vec<Vec<u32>>);
+
+fn print_queue(input: &str) -> Option<PrintQueue> {
+    let (rules, updates) = input.split_once("\n\n")?;
+    let rules = rules
+        .lines()
+        .map(|l| {
+            let (a, b) = l.split_once('|')?;
+            Some((a.parse().ok()?, b.parse().ok()?))
+        })
+        .collect::<Option<_>>()?;
+    let updates = updates
+        .lines()
+        .map(|l| l.split(',').map(|p| p.parse().ok()).collect())
+        .collect::<Option<_>>()?;
+
+    Some((rules, updates))
+}
+
+/// Whether no page in `update` comes after a page it must be printed before.
+fn in_order(rules: &BTreeSet<(u32, u32)>, update: &[u32]) -> bool {
+    (0..update.len())
+        .all(|i| (i + 1..update.len()).all(|j| !rules.contains(&(update[j], update[i]))))
+}
+
+/// The orders of `update` that break none of the rules.
+fn orders(rules: &BTreeSet<(u32, u32)>, update: &[u32]) -> Vec<Vec<u32>> {
+    permutations(update)
+        .into_iter()
+        .filter(|p| in_order(rules, p))
+        .collect()
+}
+
+/// A random order of a few pages, with only some of the rules that follow
+/// from it given. Each update still has just the one right order.
+fn sparse_print_queue(rng: &mut StdRng) -> String {
+    let mut pages: Vec<u32> = (10..100).collect();
+    pages.shuffle(rng);
+    pages.truncate(rng.gen_range(3..=8));
+
+    let mut rules = BTreeSet::new();
+    for i in 0..pages.len() {
+        for j in i + 1..pages.len() {
+            if rng.gen_bool(0.4) {
+                rules.insert((pages[i], pages[j]));
+            }
+        }
+    }
+
+    let mut updates = Vec::new();
+    for _ in 0..rng.gen_range(1..=4) {
+        let mut update: Vec<usize> = (0..pages.len()).collect();
+        update.shuffle(rng);
+        update.truncate(2 * rng.gen_range(0..=2) + 1);
+        update.truncate(pages.len() - (pages.len() + 1) % 2);
+
+        let mut sorted = update.clone();
+        sorted.sort();
+        for pair in sorted.windows(2) {
+            rules.insert((pages[pair[0]], pages[pair[1]]));
+        }
+        if rng.gen_bool(0.3) {
+            update = sorted;
+        }
+        updates.push(update.iter().map(|&i| pages[i]).collect::<Vec<_>>());
+    }
+
+    let mut rules: Vec<_> = rules.into_iter().collect();
+    rules.shuffle(rng);
+
+    let mut out = String::new();
+    for (a, b) in rules {
+        let _ = writeln!(out, "{}|{}", a, b);
+    }
+    out.push('\n');
+    for update in updates {
+        let update: Vec<String> = update.iter().map(|p| p.to_string()).collect();
+        let _ = writeln!(out, "{}", update.join(","));
+    }
+
+    out
+}
+
+#[test]
+fn test_day5() {
+    let valid = |input: &str| {
+        let Some((rules, updates)) = print_queue(input) else {
+            return false;
+        };
+
+        day5::Day5::parse(input).is_ok()
+            && updates.iter().all(|u| {
+                let pages: BTreeSet<_> = u.iter().collect();
+                u.len() % 2 == 1 && pages.len() == u.len() && orders(&rules, u).len() == 1
+            })
+    };
+
+    let reference = |input: &str| {
+        let (rules, updates) = print_queue(input).unwrap();
+        let middle = |u: &[u32]| u[u.len() / 2];
+
+        let part1 = updates
+            .iter()
+            .filter(|u| in_order(&rules, u))
+            .map(|u| middle(u))
+            .sum();
+        let part2 = updates
+            .iter()
+            .filter(|u| !in_order(&rules, u))
+            .map(|u| middle(&orders(&rules, u)[0]))
+            .sum();
+        (part1, part2)
+    };
+
+    check(compare(
+        inputs(300, sparse_print_queue),
+        valid,
+        solve::<day5::Day5>,
+        reference,
+    ));
+}
+
+fn lan(input: &str) -> BTreeMap<&str, BTreeSet<&str>> {
+    let mut computers = BTreeMap::new();
+    for (a, b) in input.lines().filter_map(|l| l.split_once('-')) {
+        computers.entry(a).or_insert(BTreeSet::new()).insert(b);
+        computers.entry(b).or_insert(BTreeSet::new()).insert(a);
+    }
+
+    computers
+}
+
+/// Every fully connected set of computers, largest first, each one sorted.
+/// They are found by growing every set found so far with each computer that
+/// sorts after all of it and is linked to all of it.
+fn cliques<'a>(computers: &BTreeMap<&'a str, BTreeSet<&'a str>>) -> Vec<Vec<&'a str>> {
+    let mut cliques = Vec::new();
+    let mut grow = vec![Vec::new()];
+    while let Some(clique) = grow.pop() {
+        for &name in computers.keys() {
+            if clique.last().is_none_or(|&last| last < name)
+                && clique.iter().all(|c| computers[c].contains(name))
+            {
+                let mut bigger = clique.clone();
+                bigger.push(name);
+                grow.push(bigger.clone());
+                cliques.push(bigger);
+            }
+        }
+    }
+    cliques.sort_by_key(|c: &Vec<&str>| std::cmp::Reverse(c.len()));
+
+    cliques
+}
+
+/// A handful of computers, a few of them starting with a `t`, linked at
+/// random.
+fn small_lan(rng: &mut StdRng) -> String {
+    let mut names: Vec<String> = "kqt"
+        .chars()
+        .flat_map(|a| "abcdef".chars().map(move |b| format!("{}{}", a, b)))
+        .collect();
+    names.shuffle(rng);
+    names.truncate(rng.gen_range(8..=16));
+
+    let density = rng.gen_range(0.3..0.8);
+    let mut links = Vec::new();
+    for a in 0..names.len() {
+        for b in a + 1..names.len() {
+            if rng.gen_bool(density) {
+                links.push(if rng.gen() { (a, b) } else { (b, a) });
+            }
+        }
+    }
+    links.shuffle(rng);
+
+    let mut out = String::new();
+    for (a, b) in links {
+        let _ = writeln!(out, "{}-{}", names[a], names[b]);
+    }
+
+    out
+}
+
+#[test]
+fn test_day23() {
+    let valid = |input: &str| {
+        let cliques = cliques(&lan(input));
+
+        day23::Day23::parse(input).is_ok()
+            && !cliques.is_empty()
+            && cliques.get(1).is_none_or(|c| c.len() < cliques[0].len())
+    };
+
+    let reference = |input: &str| {
+        let cliques = cliques(&lan(input));
+
+        let part1 = cliques
+            .iter()
+            .filter(|c| c.len() == 3 && c.iter().any(|n| n.starts_with('t')))
+            .count();
+        let part2 = cliques[0].join(",");
+        (part1, part2)
+    };
+
+    check(compare(
+        inputs(2000, small_lan),
+        valid,
+        solve::<day23::Day23>,
+        reference,
+    ));
+}
+
+/// The registers and the program of a day 17 input.
+fn computer(input: &str) -> Option<([u64; 3], Vec<u64>)> {
+    let mut lines = input.lines();
+    let mut registers = [0; 3];
+    for (register, name) in registers.iter_mut().zip(["A", "B", "C"]) {
+        let line = lines.next()?;
+        *register = line
+            .strip_prefix(&format!("Register {}: ", name))?
+            .parse()
+            .ok()?;
+    }
+    let program = lines.nth(1)?.strip_prefix("Program: ")?;
+    let program = program
+        .split(',')
+        .map(|n| n.parse().ok())
+        .collect::<Option<_>>()?;
+
+    Some((registers, program))
+}
+
+/// Runs `program` as the puzzle describes it, handing every output to `out`
+/// until it returns `false` or the program halts. `None` on an invalid combo
+/// operand, or a division that shifts by 64 bits or more.
+fn execute(
+    program: &[u64],
+    [mut a, mut b, mut c]: [u64; 3],
+    mut out: impl FnMut(u64) -> bool,
+) -> Option<()> {
+    let mut pc = 0;
+    while pc + 1 < program.len() {
+        let literal = program[pc + 1];
+        let combo = match literal {
+            0..=3 => literal,
+            4 => a,
+            5 => b,
+            6 => c,
+            _ => return None,
+        };
+        let divide = || (combo < 64).then(|| a >> combo);
+
+        match program[pc] {
+            0 => a = divide()?,
+            1 => b ^= literal,
+            2 => b = combo % 8,
+            3 if a != 0 => {
+                pc = literal as usize;
+                continue;
+            }
+            3 => {}
+            4 => b ^= c,
+            5 => {
+                if !out(combo % 8) {
+                    return Some(());
+                }
+            }
+            6 => b = divide()?,
+            _ => c = divide()?,
+        }
+        pc += 2;
+    }
+
+    Some(())
+}
+
+/// Above this, the reference gives up looking for a program's own output,
+/// so programs that need a larger A are left out.
+const LIMIT: u64 = 1 << 16;
+
+/// The lowest value for register A that makes `program` output itself.
+fn lowest_quine(program: &[u64], [_, b, c]: [u64; 3]) -> Option<u64> {
+    (1..LIMIT).find(|&a| {
+        let mut output = program.iter();
+        let mut matches = true;
+        execute(program, [a, b, c], |v| {
+            matches = output.next() == Some(&v);
+            matches
+        });
+        matches && output.next().is_none()
+    })
+}
+
+/// A loop of two or three instructions in any order, one that outputs
+/// something and one that shifts A right by one to three bits, jumping back
+/// to the start until A is zero.
+fn small_program(rng: &mut StdRng) -> String {
+    let body = [
+        [2, rng.gen_range(4..=6)],
+        [1, rng.gen_range(0..8)],
+        [4, rng.gen_range(0..8)],
+        [6, *[0, 1, 2, 3, 5].choose(rng).unwrap()],
+        [7, *[0, 1, 2, 3, 5].choose(rng).unwrap()],
+    ];
+
+    let mut instructions = vec![[5, rng.gen_range(4..=6)], [0, rng.gen_range(1..=3)]];
+    let extra = rng.gen_range(0..=1);
+    instructions.extend(body.choose_multiple(rng, extra));
+    instructions.shuffle(rng);
+    instructions.push([3, 0]);
+
+    let program: Vec<String> = instructions
+        .iter()
+        .flatten()
+        .map(|v| v.to_string())
+        .collect();
+    format!(
+        "Register A: {}\nRegister B: 0\nRegister C: 0\n\nProgram: {}\n",
+        rng.gen_range(1..1 << 12),
+        program.join(",")
+    )
+}
+
+#[test]
+fn test_day17() {
+    let valid = |input: &str| {
+        let Some((registers, program)) = computer(input) else {
+            return false;
+        };
+
+        day17::Day17::parse(input).is_ok()
+            && execute(&program, registers, |_| true).is_some()
+            && lowest_quine(&program, registers).is_some()
+    };
+
+    let reference = |input: &str| {
+        let (registers, program) = computer(input).unwrap();
+
+        let mut output = Vec::new();
+        execute(&program, registers, |v| {
+            output.push(v.to_string());
+            true
+        });
+        let part2 = lowest_quine(&program, registers).unwrap();
+        (output.join(","), part2.to_string())
+    };
+
+    check(compare(
+        inputs(600, small_program),
+        valid,
+        solve::<day17::Day17>,
+        reference,
+    ));
+}
@@ -0,0 +1,56 @@
+//! Every generated input parses and can be solved.
+
+use util::Solution;
+
+/// Solves inputs of `sizes` for `day` from a few seeds.
+fn solve<S: Solution>(day: u8, sizes: &[usize]) {
+    for seed in 0..3 {
+        let input = generate::generate(day, sizes, seed).unwrap().unwrap();
+        let input =
+            S::parse(&input).unwrap_or_else(|err| panic!("day {}, seed {}: {}", day, seed, err));
+
+        S::part1(&input);
+        S::part2(&input);
+    }
+}
+
+#[test]
+fn test_days_1_to_9() {
+    solve::<day1::Day1>(1, &[50]);
+    solve::<day2::Day2>(2, &[50]);
+    solve::<day3::Day3>(3, &[50]);
+    solve::<day4::Day4>(4, &[20]);
+    solve::<day5::Day5>(5, &[20]);
+    solve::<day6::Day6>(6, &[20]);
+    solve::<day7::Day7>(7, &[20]);
+    solve::<day8::Day8>(8, &[20]);
+    solve::<day9::Day9>(9, &[99]);
+}
+
+#[test]
+fn test_days_10_to_17() {
+    solve::<day10::Day10>(10, &[20]);
+    solve::<day11::Day11>(11, &[3]);
+    solve::<day12::Day12>(12, &[20]);
+    solve::<day13::Day13>(13, &[20]);
+    solve::<day14::Day14>(14, &[30]);
+    solve::<day15::Day15>(15, &[12]);
+    solve::<day16::Day16>(16, &[21]);
+    solve::<day17::Day17>(17, &[]);
+}
+
+#[test]
+fn test_days_18_to_25() {
+    // Part 2 tries the bytes one by one on the whole 71 by 71 space, which
+    // takes too long in a test build.
+    let input = day18::Day18::parse(&generate::generate(18, &[], 0).unwrap().unwrap()).unwrap();
+    day18::Day18::part1(&input);
+
+    solve::<day19::Day19>(19, &[20]);
+    solve::<day20::Day20>(20, &[21]);
+    solve::<day21::Day21>(21, &[5]);
+    solve::<day22::Day22>(22, &[5]);
+    solve::<day23::Day23>(23, &[40]);
+    solve::<day24::Day24>(24, &[8]);
+    solve::<day25::Day25>(25, &[20]);
+}
@@ -0,0 +1,151 @@
+/// Heap usage of one phase of a day.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    /// Number of allocations, counting reallocations.
+    pub allocations: u64,
+    /// Bytes requested by those allocations.
+    pub bytes: u64,
+    /// Most bytes live at any point, over what was live when the phase began.
+    pub peak: u64,
+}
+
+/// Runs `f` and returns its result along with the allocations it made on
+/// the current thread, or `None` if allocations aren't counted.
+pub fn measure<R>(f: impl FnOnce() -> R) -> (R, Option<AllocStats>) {
+    #[cfg(feature = "alloc-stats")]
+    {
+        let before = counting::snapshot();
+        counting::reset_peak();
+        let result = f();
+        let after = counting::snapshot();
+
+        let stats = AllocStats {
+            allocations: after.allocations - before.allocations,
+            bytes: after.bytes - before.bytes,
+            peak: (after.peak - before.live).max(0) as u64,
+        };
+        (result, Some(stats))
+    }
+
+    #[cfg(not(feature = "alloc-stats"))]
+    (f(), None)
+}
+
+/// Bytes as B, KiB, MiB or GiB.
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 3] = ["KiB", "MiB", "GiB"];
+
+    if bytes < 1024 {
+        return format!("{}B", bytes);
+    }
+
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit + 1 < UNITS.len() {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1}{}", value, UNITS[unit])
+}
+
+/// A global allocator that counts every allocation on the thread making
+/// it, so that days solved concurrently are measured separately.
+#[cfg(feature = "alloc-stats")]
+mod counting {
+    use std::{
+        alloc::{GlobalAlloc, Layout, System},
+        cell::Cell,
+    };
+
+    #[derive(Clone, Copy)]
+    pub struct Counters {
+        pub allocations: u64,
+        pub bytes: u64,
+        /// Live bytes, which go negative on a thread that frees memory
+        /// allocated by another.
+        pub live: i64,
+        pub peak: i64,
+    }
+
+    thread_local! {
+        static COUNTERS: Cell<Counters> = const {
+            Cell::new(Counters { allocations: 0, bytes: 0, live: 0, peak: 0 })
+        };
+    }
+
+    /// Ignores allocations made while the thread is being torn down.
+    fn update(f: impl FnOnce(&mut Counters)) {
+        let _ = COUNTERS.try_with(|cell| {
+            let mut counters = cell.get();
+            f(&mut counters);
+            counters.peak = counters.peak.max(counters.live);
+            cell.set(counters);
+        });
+    }
+
+    pub fn snapshot() -> Counters {
+        COUNTERS.with(|cell| cell.get())
+    }
+
+    pub fn reset_peak() {
+        update(|c| c.peak = c.live);
+    }
+
+    struct CountingAlloc;
+
+    unsafe impl GlobalAlloc for CountingAlloc {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            update(|c| {
+                c.allocations += 1;
+                c.bytes += layout.size() as u64;
+                c.live += layout.size() as i64;
+            });
+            System.alloc(layout)
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            update(|c| c.live -= layout.size() as i64);
+            System.dealloc(ptr, layout)
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            update(|c| {
+                c.allocations += 1;
+                c.bytes += new_size as u64;
+                c.live += new_size as i64 - layout.size() as i64;
+            });
+            System.realloc(ptr, layout, new_size)
+        }
+    }
+
+    #[global_allocator]
+    static GLOBAL: CountingAlloc = CountingAlloc;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(1000), "1000B");
+        assert_eq!(format_bytes(1536), "1.5KiB");
+        assert_eq!(format_bytes(3 << 30), "3.0GiB");
+    }
+
+    #[cfg(feature = "alloc-stats")]
+    #[test]
+    fn test_measure() {
+        let (_, stats) = measure(|| {
+            let a = vec![0u8; 1000];
+            drop(a);
+            let b = vec![0u8; 600];
+            b.len()
+        });
+
+        let stats = stats.unwrap();
+        assert_eq!(stats.allocations, 2);
+        assert_eq!(stats.bytes, 1600);
+        assert_eq!(stats.peak, 1000);
+    }
+}
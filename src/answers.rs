@@ -0,0 +1,119 @@
+use std::{
+    collections::HashMap,
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use util::{ParseError, Source};
+
+/// Name of the answers file, looked up next to the inputs.
+const FILE_NAME: &str = "answers.toml";
+
+/// Known answers to the real inputs, keyed by day and part.
+///
+/// The file is a small subset of TOML with one table per day:
+///
+/// ```toml
+/// [day17]
+/// part1 = "1,6,3,6,5,6,5,1,7"
+/// part2 = 247839653009594
+/// ```
+pub struct Answers {
+    answers: HashMap<(u8, u8), String>,
+}
+
+impl Answers {
+    /// `answers.toml` under `$AOC_INPUT_DIR`, or in the current directory.
+    pub fn default_path() -> PathBuf {
+        match env::var_os(util::INPUT_DIR_VAR) {
+            Some(dir) if !dir.is_empty() => PathBuf::from(dir).join(FILE_NAME),
+            _ => PathBuf::from(FILE_NAME),
+        }
+    }
+
+    pub fn load(path: &Path) -> Result<Answers, String> {
+        let data = fs::read_to_string(path)
+            .map_err(|err| format!("failed to read answers '{}': {}", path.display(), err))?;
+
+        Answers::parse(&data).map_err(|err| format!("{}:{}", path.display(), err))
+    }
+
+    pub fn parse(data: &str) -> Result<Answers, ParseError> {
+        let source = Source::new(data);
+
+        let mut answers = HashMap::new();
+        let mut day = None;
+
+        for line in source.lines().filter(|l| !l.starts_with('#')) {
+            if let Some(table) = line.strip_prefix('[') {
+                let name = table
+                    .strip_suffix(']')
+                    .ok_or_else(|| source.missing_after(line, "']'"))?;
+                let number = source.strip_prefix(name, "day")?;
+                day = Some(source.parse::<u8>(number, "a day number")?);
+                continue;
+            }
+
+            let day = day.ok_or_else(|| source.error(line, "a [dayN] table"))?;
+
+            let (key, value) = source.split_once(line, "=")?;
+            let part = source.strip_prefix(key.trim(), "part")?;
+            let part = match source.parse::<u8>(part, "1 or 2")? {
+                part @ (1 | 2) => part,
+                _ => return Err(source.error(part, "1 or 2")),
+            };
+
+            let value = value.trim();
+            let answer = match value.strip_prefix('"') {
+                Some(quoted) => quoted
+                    .strip_suffix('"')
+                    .ok_or_else(|| source.missing_after(value, "a closing '\"'"))?,
+                None => {
+                    source.parse::<i64>(value, "a number or a quoted string")?;
+                    value
+                }
+            };
+
+            answers.insert((day, part), answer.to_string());
+        }
+
+        Ok(Answers { answers })
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.answers.get(&(day, part)).map(|s| s.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse(
+            "
+            # Comments are ignored
+            [day1]
+            part1 = 1873376
+
+            [day17]
+            part1 = \"1,6,3,6,5,6,5,1,7\"
+            part2 = 247839653009594
+            ",
+        )
+        .unwrap();
+
+        assert_eq!(answers.get(1, 1), Some("1873376"));
+        assert_eq!(answers.get(1, 2), None);
+        assert_eq!(answers.get(17, 1), Some("1,6,3,6,5,6,5,1,7"));
+        assert_eq!(answers.get(17, 2), Some("247839653009594"));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Answers::parse("[day1]\npart3 = 5").err().unwrap();
+
+        assert_eq!(err.to_string(), "2:5: expected 1 or 2, found '3'");
+    }
+}
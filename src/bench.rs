@@ -0,0 +1,285 @@
+use std::{collections::HashMap, fs, path::{Path, PathBuf}, process::exit, time::Duration};
+
+use util::{ParseError, Source};
+
+use crate::{format_duration, Selection};
+
+pub struct Bench {
+    selection: Selection,
+    warmup: usize,
+    iterations: usize,
+    save: Option<PathBuf>,
+    compare: Option<PathBuf>,
+}
+
+impl Bench {
+    pub fn parse(args: &[String]) -> Result<Bench, String> {
+        let mut warmup = 1;
+        let mut iterations = 10;
+        let mut save = None;
+        let mut compare = None;
+        let mut rest = Vec::new();
+
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            let mut value = || args.next().ok_or(format!("missing value after {}", arg));
+            match arg.as_str() {
+                "--warmup" => warmup = parse_count(value()?)?,
+                "--iterations" | "-n" => iterations = parse_count(value()?)?,
+                "--save" => save = Some(PathBuf::from(value()?)),
+                "--compare" => compare = Some(PathBuf::from(value()?)),
+                _ => rest.push(arg.clone()),
+            }
+        }
+
+        if iterations == 0 {
+            return Err("--iterations must be at least 1".to_string());
+        }
+
+        Ok(Bench {
+            selection: Selection::parse(&rest, false)?,
+            warmup,
+            iterations,
+            save,
+            compare,
+        })
+    }
+
+    pub fn run(&self) {
+        let baseline = self.compare.as_ref().map(|path| {
+            Baseline::load(path).unwrap_or_else(|err| {
+                eprintln!("error: {}", err);
+                exit(1);
+            })
+        });
+
+        let mut rows = Vec::new();
+        for day in self.selection.days.iter() {
+            let path = util::input_path(day.number, self.selection.input.as_deref());
+            let input = util::read_input(&path).unwrap_or_else(|err| {
+                eprintln!("error: {}", err);
+                exit(1);
+            });
+
+            let mut samples: Vec<(String, Vec<Duration>)> = Vec::new();
+            for i in 0..self.warmup + self.iterations {
+                let report = (day.solve)(&input, &self.selection.parts).unwrap_or_else(|err| {
+                    eprintln!("error: {}:{}", path.display(), err);
+                    exit(1);
+                });
+                if i < self.warmup {
+                    continue;
+                }
+
+                let times = [("parse".to_string(), report.parse)]
+                    .into_iter()
+                    .chain(report.parts.iter().map(|p| (p.part.to_string(), p.time)));
+                for (j, (phase, time)) in times.enumerate() {
+                    match samples.get_mut(j) {
+                        Some((_, s)) => s.push(time),
+                        None => samples.push((phase, vec![time])),
+                    }
+                }
+            }
+
+            for (phase, times) in samples {
+                let stats = Stats::new(&times);
+                let baseline = baseline.as_ref().and_then(|b| b.get(day.number, &phase));
+                rows.push(Row { day: day.number, phase, stats, baseline });
+            }
+        }
+
+        print_table(&rows);
+
+        if let Some(path) = &self.save {
+            let baseline = Baseline::from_rows(&rows);
+            if let Err(err) = fs::write(path, baseline.to_string()) {
+                eprintln!("error: failed to write baseline '{}': {}", path.display(), err);
+                exit(1);
+            }
+            println!("\nSaved baseline to {}", path.display());
+        }
+    }
+}
+
+fn parse_count(s: &str) -> Result<usize, String> {
+    s.parse().map_err(|_| format!("invalid count '{}'", s))
+}
+
+/// Summary of the time samples of one phase.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn new(samples: &[Duration]) -> Stats {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let n = sorted.len();
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+
+        let secs: Vec<f64> = sorted.iter().map(|d| d.as_secs_f64()).collect();
+        let mean = secs.iter().sum::<f64>() / n as f64;
+        let variance = secs.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / n as f64;
+
+        Stats {
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+struct Row {
+    day: u8,
+    phase: String,
+    stats: Stats,
+    baseline: Option<Stats>,
+}
+
+fn print_table(rows: &[Row]) {
+    println!(
+        "{:>3}  {:>5}  {:>10}  {:>10}  {:>10}  {:>10}  {:>10}",
+        "Day", "Phase", "Min", "Median", "Mean", "Stddev", "vs base"
+    );
+    println!("{:>3}  {:>5}  {}", "---", "-----", ["----------"; 5].join("  "));
+
+    for r in rows {
+        let change = match r.baseline {
+            Some(base) if !base.median.is_zero() => {
+                let ratio = r.stats.median.as_secs_f64() / base.median.as_secs_f64();
+                format!("{:+.1}%", (ratio - 1.0) * 100.0)
+            }
+            _ => String::new(),
+        };
+
+        println!(
+            "{:>3}  {:>5}  {:>10}  {:>10}  {:>10}  {:>10}  {:>10}",
+            r.day,
+            r.phase,
+            format_duration(r.stats.min),
+            format_duration(r.stats.median),
+            format_duration(r.stats.mean),
+            format_duration(r.stats.stddev),
+            change
+        );
+    }
+}
+
+/// Timings saved by an earlier run, keyed by day and phase.
+///
+/// Stored as one line per phase: `DAY PHASE MIN MEDIAN MEAN STDDEV`, with
+/// the times in nanoseconds.
+struct Baseline {
+    stats: HashMap<(u8, String), Stats>,
+}
+
+impl Baseline {
+    fn from_rows(rows: &[Row]) -> Baseline {
+        let stats = rows.iter().map(|r| ((r.day, r.phase.clone()), r.stats)).collect();
+
+        Baseline { stats }
+    }
+
+    fn load(path: &Path) -> Result<Baseline, String> {
+        let data = fs::read_to_string(path)
+            .map_err(|err| format!("failed to read baseline '{}': {}", path.display(), err))?;
+
+        Baseline::parse(&data).map_err(|err| format!("{}:{}", path.display(), err))
+    }
+
+    fn parse(data: &str) -> Result<Baseline, ParseError> {
+        let source = Source::new(data);
+
+        let mut stats = HashMap::new();
+        for line in source.lines().filter(|l| !l.starts_with('#')) {
+            let mut fields = line.split_whitespace();
+            let mut next = |prev: &str, expected: &str| source.next(&mut fields, prev, expected);
+
+            let day = next(line, "a day")?;
+            let phase = next(day, "a phase")?;
+            let mut prev = phase;
+            let mut times = [Duration::ZERO; 4];
+            for time in times.iter_mut() {
+                prev = next(prev, "a time in nanoseconds")?;
+                *time = Duration::from_nanos(source.parse(prev, "a time in nanoseconds")?);
+            }
+
+            let [min, median, mean, stddev] = times;
+            stats.insert(
+                (source.parse(day, "a day")?, phase.to_string()),
+                Stats { min, median, mean, stddev },
+            );
+        }
+
+        Ok(Baseline { stats })
+    }
+
+    fn get(&self, day: u8, phase: &str) -> Option<Stats> {
+        self.stats.get(&(day, phase.to_string())).copied()
+    }
+}
+
+impl std::fmt::Display for Baseline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "# day phase min median mean stddev (ns)")?;
+
+        let mut keys: Vec<_> = self.stats.keys().collect();
+        keys.sort();
+        for key in keys {
+            let s = &self.stats[key];
+            writeln!(
+                f,
+                "{} {} {} {} {} {}",
+                key.0,
+                key.1,
+                s.min.as_nanos(),
+                s.median.as_nanos(),
+                s.mean.as_nanos(),
+                s.stddev.as_nanos()
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let ms = Duration::from_millis;
+        let stats = Stats::new(&[ms(4), ms(2), ms(8), ms(6)]);
+
+        assert_eq!(stats.min, ms(2));
+        assert_eq!(stats.median, ms(5));
+        assert_eq!(stats.mean, ms(5));
+        assert_eq!(stats.stddev.as_micros(), 2236);
+    }
+
+    #[test]
+    fn test_baseline_round_trip() {
+        let ms = Duration::from_millis;
+        let stats = Stats { min: ms(1), median: ms(2), mean: ms(3), stddev: ms(4) };
+        let baseline = Baseline {
+            stats: HashMap::from([((7, "parse".to_string()), stats)]),
+        };
+
+        let parsed = Baseline::parse(&baseline.to_string()).unwrap();
+
+        assert_eq!(parsed.get(7, "parse"), Some(stats));
+        assert_eq!(parsed.get(7, "1"), None);
+    }
+}
@@ -0,0 +1,109 @@
+use std::time::{Duration, Instant};
+
+use util::{
+    anim::{Animation, Sink},
+    ParseError, Solution,
+};
+
+use crate::alloc::{self, AllocStats};
+
+pub struct Day {
+    pub number: u8,
+    pub solve: fn(&str, &[u8]) -> Result<Report, ParseError>,
+}
+
+pub struct Report {
+    pub parse: Duration,
+    /// Allocations made while parsing, if they are counted.
+    pub parse_alloc: Option<AllocStats>,
+    pub parts: Vec<PartReport>,
+}
+
+pub struct PartReport {
+    pub part: u8,
+    pub answer: String,
+    pub time: Duration,
+    pub alloc: Option<AllocStats>,
+}
+
+fn solve<S: Solution>(data: &str, parts: &[u8]) -> Result<Report, ParseError> {
+    let start = Instant::now();
+    let (input, parse_alloc) = alloc::measure(|| S::parse(data));
+    let input = input?;
+    let parse = start.elapsed();
+
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let (answer, alloc) = alloc::measure(|| {
+                if part == 1 {
+                    S::part1(&input).to_string()
+                } else {
+                    S::part2(&input).to_string()
+                }
+            });
+            let time = start.elapsed();
+
+            PartReport { part, answer, time, alloc }
+        })
+        .collect();
+
+    Ok(Report { parse, parse_alloc, parts })
+}
+
+pub const DAYS: &[Day] = &[
+    Day { number: 1, solve: solve::<day1::Day1> },
+    Day { number: 2, solve: solve::<day2::Day2> },
+    Day { number: 3, solve: solve::<day3::Day3> },
+    Day { number: 4, solve: solve::<day4::Day4> },
+    Day { number: 5, solve: solve::<day5::Day5> },
+    Day { number: 6, solve: solve::<day6::Day6> },
+    Day { number: 7, solve: solve::<day7::Day7> },
+    Day { number: 8, solve: solve::<day8::Day8> },
+    Day { number: 9, solve: solve::<day9::Day9> },
+    Day { number: 10, solve: solve::<day10::Day10> },
+    Day { number: 11, solve: solve::<day11::Day11> },
+    Day { number: 12, solve: solve::<day12::Day12> },
+    Day { number: 13, solve: solve::<day13::Day13> },
+    Day { number: 14, solve: solve::<day14::Day14> },
+    Day { number: 15, solve: solve::<day15::Day15> },
+    Day { number: 16, solve: solve::<day16::Day16> },
+    Day { number: 17, solve: solve::<day17::Day17> },
+    Day { number: 18, solve: solve::<day18::Day18> },
+    Day { number: 19, solve: solve::<day19::Day19> },
+    Day { number: 20, solve: solve::<day20::Day20> },
+    Day { number: 21, solve: solve::<day21::Day21> },
+    Day { number: 22, solve: solve::<day22::Day22> },
+    Day { number: 23, solve: solve::<day23::Day23> },
+    Day { number: 24, solve: solve::<day24::Day24> },
+    Day { number: 25, solve: solve::<day25::Day25> },
+];
+
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.number == number)
+}
+
+/// A day whose simulation can be played.
+pub struct Animated {
+    pub number: u8,
+    pub animate: fn(&str, u8, &mut dyn Sink) -> Result<(), ParseError>,
+}
+
+fn animate<A: Animation>(data: &str, part: u8, sink: &mut dyn Sink) -> Result<(), ParseError> {
+    let input = A::parse(data)?;
+    A::animate(&input, part, sink);
+    Ok(())
+}
+
+pub const ANIMATED: &[Animated] = &[
+    Animated { number: 6, animate: animate::<day6::Day6> },
+    Animated { number: 9, animate: animate::<day9::Day9> },
+    Animated { number: 14, animate: animate::<day14::Day14> },
+    Animated { number: 15, animate: animate::<day15::Day15> },
+    Animated { number: 16, animate: animate::<day16::Day16> },
+];
+
+pub fn find_animated(number: u8) -> Option<&'static Animated> {
+    ANIMATED.iter().find(|d| d.number == number)
+}
@@ -0,0 +1,258 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Extracts the examples and their answers from a saved puzzle page into
+/// fixtures and a generated test file for the day.
+pub struct Examples {
+    day: u8,
+    page: String,
+    pairs: Vec<(u8, usize)>,
+}
+
+impl Examples {
+    pub fn parse(args: &[String]) -> Result<Examples, String> {
+        let mut positional = Vec::new();
+        let mut pairs = Vec::new();
+
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--pair" => {
+                    let pair = args.next().ok_or("missing PART=EXAMPLE after --pair")?;
+                    let invalid = || format!("invalid pair '{}', expected PART=EXAMPLE", pair);
+                    let (part, example) = pair.split_once('=').ok_or_else(invalid)?;
+                    match (part.parse(), example.parse()) {
+                        (Ok(part @ (1 | 2)), Ok(example @ 1..)) => pairs.push((part, example)),
+                        _ => return Err(invalid()),
+                    }
+                }
+                _ => positional.push(arg.clone()),
+            }
+        }
+
+        let (day, page) = match positional.as_slice() {
+            [day, page] => (day, page),
+            [_] | [] => return Err("expected a day and the path of a saved puzzle page".to_string()),
+            [_, _, rest, ..] => return Err(format!("unexpected argument '{}'", rest)),
+        };
+
+        Ok(Examples {
+            day: day.parse().map_err(|_| format!("invalid day '{}'", day))?,
+            page: page.clone(),
+            pairs,
+        })
+    }
+
+    pub fn run(&self) -> Result<(), String> {
+        let dir = PathBuf::from(format!("day{}", self.day));
+        if !dir.is_dir() {
+            return Err(format!("'{}' doesn't exist, create it with new-day first", dir.display()));
+        }
+
+        let html = util::read_input(&self.page).map_err(|err| err.to_string())?;
+        let mut page = Page::extract(&html);
+        if page.examples.is_empty() {
+            return Err(format!("no examples found in '{}'", self.page));
+        }
+
+        for &(part, example) in self.pairs.iter() {
+            if example > page.examples.len() {
+                return Err(format!("the page has only {} examples", page.examples.len()));
+            }
+            if let Some(answer) = page.answers.iter_mut().find(|a| a.part == part) {
+                answer.example = example;
+            }
+        }
+
+        let examples = dir.join("examples");
+        fs::create_dir_all(&examples).map_err(|err| write_error(&examples, err))?;
+        for (i, example) in page.examples.iter().enumerate() {
+            let path = examples.join(fixture_name(i + 1));
+            fs::write(&path, example).map_err(|err| write_error(&path, err))?;
+        }
+
+        let tests = dir.join("tests");
+        let path = tests.join("examples.rs");
+        fs::create_dir_all(&tests).map_err(|err| write_error(&tests, err))?;
+        fs::write(&path, generate_tests(self.day, &page.answers)).map_err(|err| write_error(&path, err))?;
+
+        println!("Wrote {} examples to {}", page.examples.len(), examples.display());
+        for answer in page.answers.iter() {
+            println!(
+                "Part {}: {} for {}",
+                answer.part,
+                answer.answer,
+                fixture_name(answer.example)
+            );
+        }
+        println!("Wrote tests to {}", path.display());
+
+        Ok(())
+    }
+}
+
+fn write_error(path: &Path, err: std::io::Error) -> String {
+    format!("failed to write '{}': {}", path.display(), err)
+}
+
+fn fixture_name(example: usize) -> String {
+    format!("example{}.txt", example)
+}
+
+/// The expected answer to one part, and the (1-based) example it is for.
+#[derive(Debug, PartialEq, Eq)]
+struct Answer {
+    part: u8,
+    answer: String,
+    example: usize,
+}
+
+/// What was found on a puzzle page.
+#[derive(Debug, PartialEq, Eq)]
+struct Page {
+    /// The text of every `<pre><code>` block, in page order.
+    examples: Vec<String>,
+    answers: Vec<Answer>,
+}
+
+impl Page {
+    /// Each part is an `<article>` on the page. Its answer is the last
+    /// emphasized `<code>` in it, and it is taken to be for the first
+    /// example in the same article, or the very first example if the
+    /// article has none (as part two usually reuses part one's).
+    fn extract(html: &str) -> Page {
+        let mut examples = Vec::new();
+        let mut answers = Vec::new();
+
+        let articles = html.split("<article").skip(1);
+        for (i, article) in articles.enumerate().take(2) {
+            let article = article.split("</article>").next().unwrap_or(article);
+
+            let first = examples.len();
+            examples.extend(between(article, "<pre><code>", "</code></pre>").map(text));
+
+            let emphasized = between(article, "<code><em>", "</em></code>")
+                .chain(between(article, "<em><code>", "</code></em>"));
+            if let Some(answer) = emphasized.max_by_key(|s| s.as_ptr()) {
+                answers.push(Answer {
+                    part: i as u8 + 1,
+                    answer: text(answer),
+                    example: if examples.len() > first { first + 1 } else { 1 },
+                });
+            }
+        }
+
+        Page { examples, answers }
+    }
+}
+
+/// Every slice of `s` between a `start` and the next `end`.
+fn between<'a>(s: &'a str, start: &'a str, end: &'a str) -> impl Iterator<Item = &'a str> {
+    s.split(start).skip(1).filter_map(move |rest| rest.split_once(end).map(|(inner, _)| inner))
+}
+
+/// `html` with tags removed and entities decoded.
+fn text(html: &str) -> String {
+    let mut stripped = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => stripped.push(c),
+            _ => {}
+        }
+    }
+
+    stripped
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+fn generate_tests(day: u8, answers: &[Answer]) -> String {
+    let mut tests = format!(
+        "// Generated by `aoc-2024 examples` from the puzzle page. Do not edit.\n\n\
+         use day{0}::Day{0};\nuse util::Solution;\n",
+        day
+    );
+
+    for answer in answers {
+        tests.push_str(&format!(
+            "\n#[test]\nfn test_part{part}_example{example}() {{\n    \
+             let input = Day{day}::parse(include_str!(\"../examples/{fixture}\")).unwrap();\n\n    \
+             assert_eq!(Day{day}::part{part}(&input).to_string(), {answer:?});\n}}\n",
+            day = day,
+            part = answer.part,
+            example = answer.example,
+            fixture = fixture_name(answer.example),
+            answer = answer.answer,
+        ));
+    }
+
+    tests
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = "<main>
+<article class=\"day-desc\"><h2>--- Day 7: Bridge Repair ---</h2>
+<p>For example:</p>
+<pre><code>190: 10 19
+3267: 81 40 27
+</code></pre>
+<p>Only <code>190</code> and <code>3267</code> can be made true, so the answer is <code><em>3457</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>1</code>.</p>
+<article class=\"day-desc\"><h2 id=\"part2\">--- Part Two ---</h2>
+<p>The <code>||</code> operator, <code>a &amp;&amp; b</code>:</p>
+<pre><code>&lt;<em>x</em>&gt;
+</code></pre>
+<p>The total is now <em><code>11387</code></em>.</p>
+</article>
+</main>";
+
+    #[test]
+    fn test_extract() {
+        let page = Page::extract(PAGE);
+
+        assert_eq!(
+            page.examples,
+            vec!["190: 10 19\n3267: 81 40 27\n".to_string(), "<x>\n".to_string()]
+        );
+        assert_eq!(
+            page.answers,
+            vec![
+                Answer { part: 1, answer: "3457".to_string(), example: 1 },
+                Answer { part: 2, answer: "11387".to_string(), example: 2 },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_generate_tests() {
+        let answers = [Answer { part: 2, answer: "11387".to_string(), example: 1 }];
+
+        assert_eq!(
+            generate_tests(7, &answers),
+            "// Generated by `aoc-2024 examples` from the puzzle page. Do not edit.
+
+use day7::Day7;
+use util::Solution;
+
+#[test]
+fn test_part2_example1() {
+    let input = Day7::parse(include_str!(\"../examples/example1.txt\")).unwrap();
+
+    assert_eq!(Day7::part2(&input).to_string(), \"11387\");
+}
+"
+        );
+    }
+}
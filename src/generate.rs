@@ -0,0 +1,54 @@
+use std::io::{self, Write};
+
+/// Prints a random input for a day.
+pub struct Generate {
+    generator: &'static generate::Generator,
+    sizes: Vec<usize>,
+    seed: u64,
+}
+
+impl Generate {
+    pub fn parse(args: &[String]) -> Result<Generate, String> {
+        let mut sizes = Vec::new();
+        let mut seed = 0;
+        let mut day = None;
+
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--size" | "-s" => {
+                    let value = args.next().ok_or("missing value after --size")?;
+                    sizes = value
+                        .split(',')
+                        .map(|n| n.parse().map_err(|_| format!("invalid size '{}'", n)))
+                        .collect::<Result<_, _>>()?;
+                }
+                "--seed" => {
+                    let value = args.next().ok_or("missing value after --seed")?;
+                    seed = value.parse().map_err(|_| format!("invalid seed '{}'", value))?;
+                }
+                s if day.is_none() => {
+                    day = Some(s.parse().map_err(|_| format!("invalid day '{}'", s))?);
+                }
+                s => return Err(format!("unexpected argument '{}'", s)),
+            }
+        }
+
+        let day = day.ok_or("missing day")?;
+        let generator = generate::find(day).ok_or(format!("no generator for day {}", day))?;
+
+        Ok(Generate {
+            generator,
+            sizes,
+            seed,
+        })
+    }
+
+    pub fn run(&self) -> Result<(), String> {
+        let input = self.generator.generate(&mut generate::rng(self.seed), &self.sizes)?;
+
+        io::stdout()
+            .write_all(input.as_bytes())
+            .map_err(|err| err.to_string())
+    }
+}
@@ -1,3 +1,357 @@
+use std::{
+    env,
+    process::exit,
+    time::{Duration, Instant},
+};
+
+mod alloc;
+mod answers;
+mod bench;
+mod days;
+mod examples;
+mod generate;
+mod output;
+mod play;
+mod pool;
+mod record;
+mod scaffold;
+mod submit;
+mod verify;
+#[cfg(target_os = "linux")]
+mod watch;
+
+use bench::Bench;
+use days::{Day, Report};
+use examples::Examples;
+use generate::Generate;
+use output::{DayResult, Format, Status};
+use play::Play;
+use record::Record;
+use scaffold::NewDay;
+use submit::Submit;
+use verify::Verify;
+#[cfg(target_os = "linux")]
+use watch::Watch;
+
+const USAGE: &str = "\
+Usage: aoc-2024 run <DAY|all> [PART] [--input PATH] [--jobs N]
+                    [--format table|json|csv|markdown]
+       aoc-2024 verify [DAY|all] [PART] [--answers PATH]
+       aoc-2024 bench <DAY|all> [PART] [--iterations N] [--warmup N]
+                      [--save PATH] [--compare PATH]
+       aoc-2024 new-day <DAY> [--example PATH] [--root DIR]
+       aoc-2024 examples <DAY> <PAGE> [--pair PART=EXAMPLE]
+       aoc-2024 fetch <DAY|all>
+       aoc-2024 submit <DAY> <PART> [ANSWER] [--input PATH]
+       aoc-2024 watch <DAY> [--input PATH]
+       aoc-2024 play <DAY> [PART] [--input PATH] [--delay MS]
+       aoc-2024 record <DAY> [PART] --out FILE.gif [--input PATH] [--skip N]
+                       [--max-frames N] [--scale N] [--delay MS]
+       aoc-2024 generate <DAY> [--size N[,N...]] [--seed S]
+
+run solves one day (or every day) and prints a table of the answers. PART
+is 1 or 2; both parts are run when it is omitted. Days are solved
+concurrently on N threads (one per CPU by default). A day that fails or
+panics is reported in the table without stopping the others, and makes
+the exit status 1. --format prints the results as JSON, CSV or a Markdown
+table instead, with the times in nanoseconds in JSON and CSV. When built
+with --features alloc-stats, every format also shows the allocations,
+bytes allocated and peak live bytes of each phase.
+
+verify solves every day (or the given one) and compares the answers with
+the known ones in answers.toml, exiting with status 1 on any mismatch.
+The answers file is looked up next to the inputs unless --answers is given.
+
+bench times parsing and each part separately over N iterations (10 by
+default) after a number of untimed warmup runs (1 by default), and prints
+min/median/mean/stddev. --save writes the timings to a baseline file and
+--compare shows the change in median against one.
+
+new-day creates dayN from the dayXX template and registers it with the
+runner. It must be run from the workspace root, or given it with --root,
+such as a copy of this workspace for a new year with the days taken out.
+A day that is already registered is refused. --example pastes the example
+input at PATH (or stdin if PATH is -) into the INPUT test constant.
+
+examples reads a saved puzzle page and writes its <pre><code> blocks to
+dayN/examples/exampleK.txt, and a test per part checking the emphasized
+answer to dayN/tests/examples.rs. Each part's answer is checked against the
+first example in that part, or the first example on the page; --pair
+checks a part against another example instead.
+
+fetch downloads the input for a day (or every day) into the cache, using
+the session cookie in $AOC_SESSION. The cache is ~/.cache/aoc-2024 unless
+$AOC_CACHE_DIR is set, and inputs already in it are never downloaded
+again. $AOC_BASE_URL points the download at another server.
+
+submit sends an answer (by default the one computed from the input) to the
+same server and prints the verdict. Every answer sent is recorded in the
+cache, so an answer is never sent twice, nor one that an earlier answer
+already shows is too high or too low. The wait the site imposes after a
+wrong answer is kept locally as well.
+
+watch builds and runs dayN with cargo, then runs its tests, and does it
+again whenever a file in dayN/src changes, or the input, wherever --input
+or $AOC_INPUT_DIR puts it. Each run shows how the answers compare to the
+previous one. It must be run from the workspace root, and is only
+available on Linux.
+
+play shows the simulation behind a part of day 6, 9, 14, 15 or 16 in the
+terminal, one frame every MS milliseconds (100 by default), with whatever
+just moved in reverse video. Space pauses, the arrow keys step forward and
+back through the last 1000 frames, + and - change the speed and q quits.
+
+record writes the same simulation to an animated GIF instead, keeping one
+frame and then dropping N (none by default), and stopping after
+--max-frames. Each cell is N pixels wide (4 by default) and each frame is
+shown for MS milliseconds (100 by default).
+
+generate prints a random input for a day, for trying a solution on inputs
+larger or stranger than the real one, e.g. by piping it into run --input -.
+What N counts depends on the day, such as lines or the side of a map, and
+it is about the size of the real input by default. Day 18 takes the side
+of the memory space and then how many bytes fall before the exit can be
+cut off, e.g. --size 7,12, and day 17 takes no size at all. The same seed
+S (0 by default) always gives the same input.
+
+Inputs are read from dayN/input.txt under $AOC_INPUT_DIR, or under the
+current directory if it isn't set. --input reads a single day's input from
+PATH instead, or from stdin if PATH is -. A missing dayN/input.txt is
+taken from the cache, downloading it first if $AOC_SESSION is set.";
+
 fn main() {
-    println!("Run 'cargo run -p DAY'");
-}
\ No newline at end of file
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    match args.first().map(|s| s.as_str()) {
+        Some("run") => match Run::parse(&args[1..]) {
+            Ok(run) => exit(if run.run() { 0 } else { 1 }),
+            Err(msg) => fail(&msg),
+        },
+        Some("verify") => match Verify::parse(&args[1..]) {
+            Ok(verify) => exit(verify.run()),
+            Err(msg) => fail(&msg),
+        },
+        Some("bench") => match Bench::parse(&args[1..]) {
+            Ok(bench) => bench.run(),
+            Err(msg) => fail(&msg),
+        },
+        Some("new-day") => match NewDay::parse(&args[1..]) {
+            Ok(new_day) => new_day.run().unwrap_or_else(|err| {
+                eprintln!("error: {}", err);
+                exit(1);
+            }),
+            Err(msg) => fail(&msg),
+        },
+        Some("examples") => match Examples::parse(&args[1..]) {
+            Ok(examples) => examples.run().unwrap_or_else(|err| {
+                eprintln!("error: {}", err);
+                exit(1);
+            }),
+            Err(msg) => fail(&msg),
+        },
+        Some("fetch") => match Selection::parse(&args[1..], false) {
+            Ok(selection) if selection.input.is_some() => fail("--input can't be used with fetch"),
+            Ok(_) if args.len() > 2 => fail(&format!("unexpected argument '{}'", args[2])),
+            Ok(selection) => fetch(&selection),
+            Err(msg) => fail(&msg),
+        },
+        Some("submit") => match Submit::parse(&args[1..]) {
+            Ok(submit) => match submit.run() {
+                Ok(correct) => exit(if correct { 0 } else { 1 }),
+                Err(err) => {
+                    eprintln!("error: {}", err);
+                    exit(1);
+                }
+            },
+            Err(msg) => fail(&msg),
+        },
+        #[cfg(target_os = "linux")]
+        Some("watch") => match Watch::parse(&args[1..]) {
+            Ok(watch) => watch.run().unwrap_or_else(|err| {
+                eprintln!("error: {}", err);
+                exit(1);
+            }),
+            Err(msg) => fail(&msg),
+        },
+        Some("play") => match Play::parse(&args[1..]) {
+            Ok(play) => play.run().unwrap_or_else(|err| {
+                eprintln!("error: {}", err);
+                exit(1);
+            }),
+            Err(msg) => fail(&msg),
+        },
+        Some("record") => match Record::parse(&args[1..]) {
+            Ok(record) => record.run().unwrap_or_else(|err| {
+                eprintln!("error: {}", err);
+                exit(1);
+            }),
+            Err(msg) => fail(&msg),
+        },
+        Some("generate") => match Generate::parse(&args[1..]) {
+            Ok(generate) => generate.run().unwrap_or_else(|err| {
+                eprintln!("error: {}", err);
+                exit(1);
+            }),
+            Err(msg) => fail(&msg),
+        },
+        Some("help") | Some("-h") | Some("--help") => println!("{}", USAGE),
+        Some(cmd) => fail(&format!("unknown command '{}'", cmd)),
+        None => fail("missing command"),
+    }
+}
+
+fn fail(msg: &str) -> ! {
+    eprintln!("error: {}\n\n{}", msg, USAGE);
+    exit(2);
+}
+
+struct Selection {
+    days: Vec<&'static Day>,
+    parts: Vec<u8>,
+    input: Option<String>,
+}
+
+impl Selection {
+    fn parse(args: &[String], all_by_default: bool) -> Result<Selection, String> {
+        let mut input = None;
+        let mut positional = Vec::new();
+
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--input" | "-i" => {
+                    let path = args.next().ok_or("missing path after --input")?;
+                    input = Some(path.clone());
+                }
+                _ => positional.push(arg.clone()),
+            }
+        }
+        let args = positional;
+
+        let days = match args.first().map(|s| s.as_str()) {
+            Some("all") => days::DAYS.iter().collect(),
+            Some(s) => {
+                let number = s.parse().map_err(|_| format!("invalid day '{}'", s))?;
+                let day = days::find(number).ok_or(format!("no solution for day {}", number))?;
+                vec![day]
+            }
+            None if all_by_default => days::DAYS.iter().collect(),
+            None => return Err("missing day".to_string()),
+        };
+
+        let parts = match args.get(1).map(|s| s.as_str()) {
+            Some("1") => vec![1],
+            Some("2") => vec![2],
+            Some(s) => return Err(format!("invalid part '{}'", s)),
+            None => vec![1, 2],
+        };
+
+        if args.len() > 2 {
+            return Err(format!("unexpected argument '{}'", args[2]));
+        }
+
+        if input.is_some() && days.len() > 1 {
+            return Err("--input can only be used with a single day".to_string());
+        }
+
+        Ok(Selection { days, parts, input })
+    }
+
+    fn solve(&self, day: &Day) -> Result<Report, String> {
+        let path = util::input_path(day.number, self.input.as_deref());
+        let input = util::read_input(&path).map_err(|err| err.to_string())?;
+
+        (day.solve)(&input, &self.parts).map_err(|err| format!("{}:{}", path.display(), err))
+    }
+}
+
+/// Solves the selected days concurrently.
+struct Run {
+    selection: Selection,
+    jobs: usize,
+    format: Format,
+}
+
+impl Run {
+    fn parse(args: &[String]) -> Result<Run, String> {
+        let mut jobs = pool::default_jobs();
+        let mut format = Format::Table;
+        let mut rest = Vec::new();
+
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--jobs" | "-j" => {
+                    let value = args.next().ok_or("missing value after --jobs")?;
+                    jobs = value
+                        .parse()
+                        .ok()
+                        .filter(|&n| n > 0)
+                        .ok_or(format!("invalid number of jobs '{}'", value))?;
+                }
+                "--format" | "-f" => {
+                    format = Format::parse(args.next().ok_or("missing value after --format")?)?;
+                }
+                _ => rest.push(arg.clone()),
+            }
+        }
+
+        Ok(Run {
+            selection: Selection::parse(&rest, false)?,
+            jobs,
+            format,
+        })
+    }
+
+    /// Prints a summary of every day and returns whether they all succeeded.
+    fn run(&self) -> bool {
+        let start = Instant::now();
+        let results = pool::map(&self.selection.days, self.jobs, |day| {
+            self.selection.solve(day)
+        });
+        let wall = start.elapsed();
+
+        let results: Vec<DayResult> = self
+            .selection
+            .days
+            .iter()
+            .zip(results)
+            .map(|(day, (result, wall))| DayResult {
+                day: day.number,
+                status: match result {
+                    Ok(Ok(report)) => Status::Solved(report),
+                    Ok(Err(err)) => Status::Failed(err),
+                    Err(msg) => Status::Panicked(msg),
+                },
+                wall,
+            })
+            .collect();
+
+        print!("{}", output::render(self.format, &results, wall));
+
+        results.iter().all(|r| matches!(r.status, Status::Solved(_)))
+    }
+}
+
+fn fetch(selection: &Selection) {
+    let fetcher = util::Fetcher::from_env();
+
+    for day in selection.days.iter() {
+        if let Err(err) = fetcher.fetch(day.number) {
+            eprintln!("error: day {}: {}", day.number, err);
+            exit(1);
+        }
+        println!("Day {}: {}", day.number, fetcher.cache_path(day.number).display());
+    }
+}
+
+fn format_duration(d: Duration) -> String {
+    let micros = d.as_micros();
+    if micros < 1_000 {
+        format!("{}µs", micros)
+    } else if micros < 1_000_000 {
+        format!("{:.2}ms", micros as f64 / 1_000.0)
+    } else {
+        format!("{:.2}s", micros as f64 / 1_000_000.0)
+    }
+}
@@ -0,0 +1,588 @@
+use std::{fmt::Write, time::Duration};
+
+use crate::{
+    alloc::{format_bytes, AllocStats},
+    days::Report,
+    format_duration,
+};
+
+/// How `run` prints its results.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Table,
+    Json,
+    Csv,
+    Markdown,
+}
+
+impl Format {
+    pub fn parse(s: &str) -> Result<Format, String> {
+        match s {
+            "table" => Ok(Format::Table),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            "markdown" | "md" => Ok(Format::Markdown),
+            _ => Err(format!(
+                "invalid format '{}', expected table, json, csv or markdown",
+                s
+            )),
+        }
+    }
+}
+
+/// How solving one day went.
+pub enum Status {
+    Solved(Report),
+    /// The input couldn't be read or parsed.
+    Failed(String),
+    Panicked(String),
+}
+
+pub struct DayResult {
+    pub day: u8,
+    pub status: Status,
+    /// Time from starting to read the input to having the answers.
+    pub wall: Duration,
+}
+
+/// Renders the results of a run that took `wall` in total.
+pub fn render(format: Format, results: &[DayResult], wall: Duration) -> String {
+    match format {
+        Format::Table => table(results, wall),
+        Format::Json => json(results, wall),
+        Format::Csv => csv(results),
+        Format::Markdown => markdown(results, wall),
+    }
+}
+
+/// A row of the table and Markdown outputs.
+struct Row {
+    day: u8,
+    phase: String,
+    answer: String,
+    time: Option<Duration>,
+    /// Wall time of the whole day, shown on its first row.
+    wall: Option<Duration>,
+    alloc: Option<AllocStats>,
+}
+
+fn rows(results: &[DayResult]) -> Vec<Row> {
+    let mut rows = Vec::new();
+
+    for r in results {
+        let report = match &r.status {
+            Status::Solved(report) => report,
+            Status::Failed(err) => {
+                rows.push(Row::failure(r.day, format!("error: {}", err), r.wall));
+                continue;
+            }
+            Status::Panicked(msg) => {
+                rows.push(Row::failure(r.day, format!("panicked: {}", msg), r.wall));
+                continue;
+            }
+        };
+
+        rows.push(Row {
+            day: r.day,
+            phase: "parse".to_string(),
+            answer: String::new(),
+            time: Some(report.parse),
+            wall: Some(r.wall),
+            alloc: report.parse_alloc,
+        });
+
+        for part in report.parts.iter() {
+            rows.push(Row {
+                day: r.day,
+                phase: part.part.to_string(),
+                answer: part.answer.clone(),
+                time: Some(part.time),
+                wall: None,
+                alloc: part.alloc,
+            });
+        }
+    }
+
+    rows
+}
+
+impl Row {
+    fn failure(day: u8, answer: String, wall: Duration) -> Row {
+        Row {
+            day,
+            phase: String::new(),
+            answer,
+            time: None,
+            wall: Some(wall),
+            alloc: None,
+        }
+    }
+}
+
+fn duration(d: Option<Duration>) -> String {
+    d.map(format_duration).unwrap_or_default()
+}
+
+/// Whether any row has allocation counts, i.e. the columns for them are
+/// worth showing.
+fn has_alloc(rows: &[Row]) -> bool {
+    rows.iter().any(|r| r.alloc.is_some())
+}
+
+/// The allocation columns of a row: count, bytes and peak.
+fn alloc_columns(alloc: Option<AllocStats>) -> [String; 3] {
+    match alloc {
+        Some(a) => [
+            a.allocations.to_string(),
+            format_bytes(a.bytes),
+            format_bytes(a.peak),
+        ],
+        None => Default::default(),
+    }
+}
+
+/// The rows with the total time spent in the days, and the `wall` time the
+/// whole run took.
+fn table(results: &[DayResult], wall: Duration) -> String {
+    let rows = rows(results);
+    let width = rows
+        .iter()
+        .map(|r| r.answer.chars().count())
+        .chain(["Answer".len()])
+        .max()
+        .unwrap_or(0);
+
+    let alloc = has_alloc(&rows);
+
+    let mut out = String::new();
+    let mut line = |day: &dyn ToString,
+                    phase: &str,
+                    answer: &str,
+                    time: &str,
+                    wall: &str,
+                    allocs: [&str; 3]| {
+        let mut line = format!(
+            "{:>3}  {:>5}  {:<width$}  {:>10}  {:>10}",
+            day.to_string(),
+            phase,
+            answer,
+            time,
+            wall
+        );
+        if alloc {
+            line += &format!("  {:>10}  {:>10}  {:>10}", allocs[0], allocs[1], allocs[2]);
+        }
+        writeln!(out, "{}", line.trim_end()).unwrap();
+    };
+
+    let dashes = "-".repeat(10);
+    line(
+        &"Day",
+        "Part",
+        "Answer",
+        "Time",
+        "Wall",
+        ["Allocs", "Bytes", "Peak"],
+    );
+    line(
+        &"---",
+        "-----",
+        &"-".repeat(width),
+        &dashes,
+        &dashes,
+        [&dashes; 3],
+    );
+    for r in rows.iter() {
+        let [count, bytes, peak] = alloc_columns(r.alloc);
+        line(
+            &r.day,
+            &r.phase,
+            &r.answer,
+            &duration(r.time),
+            &duration(r.wall),
+            [&count, &bytes, &peak],
+        );
+    }
+    line(
+        &"",
+        "",
+        "Total",
+        &format_duration(total(results)),
+        &format_duration(wall),
+        [""; 3],
+    );
+
+    out
+}
+
+fn total(results: &[DayResult]) -> Duration {
+    results.iter().map(|r| r.wall).sum()
+}
+
+/// A Markdown table, as the plain table but with the answers in code spans.
+fn markdown(results: &[DayResult], wall: Duration) -> String {
+    let rows = rows(results);
+    let alloc = has_alloc(&rows);
+
+    let mut out = String::new();
+    if alloc {
+        writeln!(
+            out,
+            "| Day | Part | Answer | Time | Wall | Allocs | Bytes | Peak |"
+        )
+        .unwrap();
+        writeln!(
+            out,
+            "|----:|-----:|:-------|-----:|-----:|-------:|------:|-----:|"
+        )
+        .unwrap();
+    } else {
+        writeln!(out, "| Day | Part | Answer | Time | Wall |").unwrap();
+        writeln!(out, "|----:|-----:|:-------|-----:|-----:|").unwrap();
+    }
+
+    for r in rows {
+        let answer = match (r.answer.is_empty(), r.phase.is_empty()) {
+            (true, _) => String::new(),
+            (false, true) => format!("*{}*", markdown_text(&r.answer)),
+            (false, false) => markdown_code(&r.answer),
+        };
+        write!(
+            out,
+            "| {} | {} | {} | {} | {} |",
+            r.day,
+            r.phase,
+            answer,
+            duration(r.time),
+            duration(r.wall)
+        )
+        .unwrap();
+        if alloc {
+            let [count, bytes, peak] = alloc_columns(r.alloc);
+            write!(out, " {} | {} | {} |", count, bytes, peak).unwrap();
+        }
+        out.push('\n');
+    }
+
+    write!(
+        out,
+        "| | | **Total** | {} | {} |",
+        format_duration(total(results)),
+        format_duration(wall)
+    )
+    .unwrap();
+    out.push_str(if alloc { " | | |\n" } else { "\n" });
+
+    out
+}
+
+/// `s` as a code span in a table cell. The span is fenced by more backticks
+/// than `s` has in a row, and line breaks become spaces, as they would in
+/// any code span.
+fn markdown_code(s: &str) -> String {
+    let s = s.replace("\r\n", " ").replace(['\r', '\n'], " ").replace('|', "\\|");
+
+    let longest = s.split(|c| c != '`').map(str::len).max().unwrap_or(0);
+    let fence = "`".repeat(longest + 1);
+    let pad = if s.starts_with('`') || s.ends_with('`') { " " } else { "" };
+
+    format!("{}{}{}{}{}", fence, pad, s, pad, fence)
+}
+
+/// `s` as plain text in a table cell, with whatever Markdown would take as
+/// formatting escaped, and line breaks as `<br>`.
+fn markdown_text(s: &str) -> String {
+    let mut out = String::new();
+    for line in s.lines() {
+        if !out.is_empty() {
+            out.push_str("<br>");
+        }
+        for c in line.chars() {
+            match c {
+                '\\' | '`' | '*' | '_' | '|' | '<' | '[' | ']' => {
+                    out.push('\\');
+                    out.push(c);
+                }
+                '\r' => out.push(' '),
+                c => out.push(c),
+            }
+        }
+    }
+    out
+}
+
+/// One line per phase, with times in nanoseconds. A `total` phase holds the
+/// day's wall time, and the error of a day that failed. Allocation counts,
+/// when there are any, are in the last three columns.
+fn csv(results: &[DayResult]) -> String {
+    let alloc = has_alloc(&rows(results));
+
+    let mut out = String::from("day,phase,status,answer,time_ns");
+    out.push_str(if alloc {
+        ",allocations,bytes,peak_bytes\n"
+    } else {
+        "\n"
+    });
+
+    let mut line = |day: u8,
+                    phase: &str,
+                    status: &str,
+                    answer: &str,
+                    time: Duration,
+                    stats: Option<AllocStats>| {
+        write!(
+            out,
+            "{},{},{},{},{}",
+            day,
+            phase,
+            status,
+            csv_field(answer),
+            time.as_nanos()
+        )
+        .unwrap();
+        match stats {
+            Some(s) if alloc => write!(out, ",{},{},{}", s.allocations, s.bytes, s.peak).unwrap(),
+            None if alloc => out.push_str(",,,"),
+            _ => {}
+        }
+        out.push('\n');
+    };
+
+    for r in results {
+        match &r.status {
+            Status::Solved(report) => {
+                line(r.day, "parse", "ok", "", report.parse, report.parse_alloc);
+                for part in report.parts.iter() {
+                    line(
+                        r.day,
+                        &part.part.to_string(),
+                        "ok",
+                        &part.answer,
+                        part.time,
+                        part.alloc,
+                    );
+                }
+                line(r.day, "total", "ok", "", r.wall, None);
+            }
+            Status::Failed(err) => line(r.day, "total", "error", err, r.wall, None),
+            Status::Panicked(msg) => line(r.day, "total", "panicked", msg, r.wall, None),
+        }
+    }
+
+    out
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+/// An object with the run's wall time and one entry per day, with times in
+/// nanoseconds and answers as strings. Phases get an `alloc` object when
+/// allocations are counted.
+fn json(results: &[DayResult], wall: Duration) -> String {
+    let mut out = String::from("{\n");
+    writeln!(out, "  \"wall_ns\": {},", wall.as_nanos()).unwrap();
+    writeln!(out, "  \"days\": [").unwrap();
+
+    for (i, r) in results.iter().enumerate() {
+        write!(out, "    {{\"day\": {}, ", r.day).unwrap();
+        match &r.status {
+            Status::Solved(report) => {
+                write!(
+                    out,
+                    "\"status\": \"ok\", \"wall_ns\": {}, ",
+                    r.wall.as_nanos()
+                )
+                .unwrap();
+                write!(out, "\"parse_ns\": {}, ", report.parse.as_nanos()).unwrap();
+                if let Some(stats) = report.parse_alloc {
+                    write!(out, "\"parse_alloc\": {}, ", json_alloc(stats)).unwrap();
+                }
+                out.push_str("\"parts\": [");
+                for (j, part) in report.parts.iter().enumerate() {
+                    if j > 0 {
+                        out.push_str(", ");
+                    }
+                    write!(
+                        out,
+                        "{{\"part\": {}, \"answer\": {}, \"time_ns\": {}",
+                        part.part,
+                        json_string(&part.answer),
+                        part.time.as_nanos()
+                    )
+                    .unwrap();
+                    if let Some(stats) = part.alloc {
+                        write!(out, ", \"alloc\": {}", json_alloc(stats)).unwrap();
+                    }
+                    out.push('}');
+                }
+                out.push_str("]}");
+            }
+            Status::Failed(err) | Status::Panicked(err) => {
+                let status = if matches!(r.status, Status::Failed(_)) {
+                    "error"
+                } else {
+                    "panicked"
+                };
+                write!(
+                    out,
+                    "\"status\": \"{}\", \"wall_ns\": {}, \"error\": {}}}",
+                    status,
+                    r.wall.as_nanos(),
+                    json_string(err)
+                )
+                .unwrap();
+            }
+        }
+        out.push_str(if i + 1 < results.len() { ",\n" } else { "\n" });
+    }
+
+    out.push_str("  ]\n}\n");
+    out
+}
+
+fn json_alloc(stats: AllocStats) -> String {
+    format!(
+        "{{\"allocations\": {}, \"bytes\": {}, \"peak_bytes\": {}}}",
+        stats.allocations, stats.bytes, stats.peak
+    )
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::PartReport;
+
+    fn results() -> Vec<DayResult> {
+        let us = Duration::from_micros;
+
+        vec![
+            DayResult {
+                day: 17,
+                status: Status::Solved(Report {
+                    parse: us(2),
+                    parse_alloc: None,
+                    parts: vec![PartReport {
+                        part: 1,
+                        answer: "1,6,3".to_string(),
+                        time: us(3),
+                        alloc: None,
+                    }],
+                }),
+                wall: us(10),
+            },
+            DayResult {
+                day: 18,
+                status: Status::Panicked("this is \"impossible\"!".to_string()),
+                wall: us(5),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_table_with_alloc() {
+        let mut results = results();
+        if let Status::Solved(report) = &mut results[0].status {
+            report.parts[0].alloc = Some(AllocStats {
+                allocations: 4,
+                bytes: 2048,
+                peak: 1536,
+            });
+        }
+
+        let table = render(Format::Table, &results, Duration::from_micros(12));
+        let lines: Vec<&str> = table.lines().collect();
+
+        assert!(
+            lines[0].ends_with("Wall      Allocs       Bytes        Peak"),
+            "{}",
+            table
+        );
+        assert!(
+            lines[3].ends_with("3µs                       4      2.0KiB      1.5KiB"),
+            "{}",
+            table
+        );
+        assert!(lines[2].ends_with("10µs"), "{}", table);
+    }
+
+    #[test]
+    fn test_json() {
+        assert_eq!(
+            render(Format::Json, &results(), Duration::from_micros(12)),
+            r#"{
+  "wall_ns": 12000,
+  "days": [
+    {"day": 17, "status": "ok", "wall_ns": 10000, "parse_ns": 2000, "parts": [{"part": 1, "answer": "1,6,3", "time_ns": 3000}]},
+    {"day": 18, "status": "panicked", "wall_ns": 5000, "error": "this is \"impossible\"!"}
+  ]
+}
+"#
+        );
+    }
+
+    #[test]
+    fn test_csv() {
+        assert_eq!(
+            render(Format::Csv, &results(), Duration::ZERO),
+            "day,phase,status,answer,time_ns
+17,parse,ok,,2000
+17,1,ok,\"1,6,3\",3000
+17,total,ok,,10000
+18,total,panicked,\"this is \"\"impossible\"\"!\",5000
+"
+        );
+    }
+
+    #[test]
+    fn test_markdown() {
+        assert_eq!(
+            render(Format::Markdown, &results(), Duration::from_micros(12)),
+            "| Day | Part | Answer | Time | Wall |
+|----:|-----:|:-------|-----:|-----:|
+| 17 | parse |  | 2µs | 10µs |
+| 17 | 1 | `1,6,3` | 3µs |  |
+| 18 |  | *panicked: this is \"impossible\"!* |  | 5µs |
+| | | **Total** | 15µs | 12µs |
+"
+        );
+    }
+
+    #[test]
+    fn test_markdown_escapes() {
+        assert_eq!(markdown_code("a|b"), "`a\\|b`");
+        assert_eq!(markdown_code("a`b``c"), "```a`b``c```");
+        assert_eq!(markdown_code("`a"), "`` `a ``");
+        assert_eq!(markdown_code("1\n2\r\n3"), "`1 2 3`");
+
+        assert_eq!(markdown_text("a|b *c* [d]"), "a\\|b \\*c\\* \\[d\\]");
+        assert_eq!(markdown_text("line 1\r\nline 2\n`x`"), "line 1<br>line 2<br>\\`x\\`");
+    }
+
+    #[test]
+    fn test_csv_field() {
+        assert_eq!(csv_field("abc"), "abc");
+        assert_eq!(csv_field("a\rb"), "\"a\rb\"");
+        assert_eq!(csv_field("a\nb"), "\"a\nb\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+}
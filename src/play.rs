@@ -0,0 +1,323 @@
+use std::{
+    collections::VecDeque,
+    fmt::Write as _,
+    io::{self, Read, Write},
+    process::{Command, Stdio},
+    thread,
+    time::{Duration, Instant},
+};
+
+use util::anim::{Frame, Sink};
+
+use crate::{days, Selection};
+
+/// How many of the latest frames can be stepped back to.
+const HISTORY: usize = 1000;
+
+const MIN_DELAY: Duration = Duration::from_millis(1);
+const MAX_DELAY: Duration = Duration::from_secs(2);
+
+/// How often the keyboard is checked while waiting for the next frame.
+const POLL: Duration = Duration::from_millis(10);
+
+const HELP: &str = "space play/pause, \u{2190}/\u{2192} step, +/- speed, q quit";
+
+/// Plays the simulation behind one part of a day in the terminal.
+pub struct Play {
+    selection: Selection,
+    delay: Duration,
+}
+
+impl Play {
+    pub fn parse(args: &[String]) -> Result<Play, String> {
+        let mut delay = Duration::from_millis(100);
+        let mut rest = Vec::new();
+
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--delay" | "-d" => {
+                    let value = args.next().ok_or("missing value after --delay")?;
+                    let ms = value
+                        .parse()
+                        .map_err(|_| format!("invalid delay '{}'", value))?;
+                    delay = Duration::from_millis(ms).clamp(MIN_DELAY, MAX_DELAY);
+                }
+                _ => rest.push(arg.clone()),
+            }
+        }
+
+        let selection = Selection::parse(&rest, false)?;
+        if selection.days.len() != 1 {
+            return Err("play takes a single day".to_string());
+        }
+
+        Ok(Play { selection, delay })
+    }
+
+    pub fn run(&self) -> Result<(), String> {
+        let number = self.selection.days[0].number;
+        let animated = days::find_animated(number)
+            .ok_or(format!("day {} has no animation", number))?;
+
+        let path = util::input_path(number, self.selection.input.as_deref());
+        let input = util::read_input(&path).map_err(|err| err.to_string())?;
+
+        let mut player = Player::new(Terminal::open()?, self.delay);
+        (animated.animate)(&input, self.selection.parts[0], &mut player)
+            .map_err(|err| format!("{}:{}", path.display(), err))?;
+        player.finish();
+
+        Ok(())
+    }
+}
+
+/// Shows frames as they are pushed, keeping the latest ones so that the
+/// viewer can step back through them.
+struct Player {
+    terminal: Terminal,
+    history: VecDeque<Frame>,
+    /// Index into `history` of the frame on screen.
+    current: usize,
+    playing: bool,
+    delay: Duration,
+    /// Set once the simulation has pushed its last frame.
+    done: bool,
+    quit: bool,
+}
+
+impl Player {
+    fn new(terminal: Terminal, delay: Duration) -> Self {
+        Player {
+            terminal,
+            history: VecDeque::new(),
+            current: 0,
+            playing: true,
+            delay,
+            done: false,
+            quit: false,
+        }
+    }
+
+    /// Keeps showing frames until the viewer quits.
+    fn finish(&mut self) {
+        self.done = true;
+        if !self.quit && !self.history.is_empty() {
+            self.show();
+        }
+    }
+
+    /// Shows frames from the history until the viewer moves past the
+    /// latest one, which returns `true`, or quits, which returns `false`.
+    fn show(&mut self) -> bool {
+        loop {
+            self.draw();
+
+            let next = Instant::now() + self.delay;
+            loop {
+                match self.terminal.key() {
+                    Some(Key::Quit) => {
+                        self.quit = true;
+                        return false;
+                    }
+                    Some(Key::Pause) => self.playing = !self.playing,
+                    Some(Key::Forward) => {
+                        self.playing = false;
+                        if self.advance() {
+                            return true;
+                        }
+                    }
+                    Some(Key::Back) => {
+                        self.playing = false;
+                        self.current = self.current.saturating_sub(1);
+                    }
+                    Some(Key::Faster) => self.delay = (self.delay / 2).max(MIN_DELAY),
+                    Some(Key::Slower) => self.delay = (self.delay * 2).min(MAX_DELAY),
+                    None if self.playing && Instant::now() >= next => {
+                        if self.advance() {
+                            return true;
+                        }
+                    }
+                    None => {
+                        thread::sleep(POLL);
+                        continue;
+                    }
+                }
+                break;
+            }
+        }
+    }
+
+    /// Moves to the next frame, returning whether that needs a new one from
+    /// the simulation.
+    fn advance(&mut self) -> bool {
+        if self.current + 1 < self.history.len() {
+            self.current += 1;
+            false
+        } else if self.done {
+            self.playing = false;
+            false
+        } else {
+            true
+        }
+    }
+
+    fn draw(&mut self) {
+        let frame = &self.history[self.current];
+        let state = if self.playing {
+            format!("playing, {}ms per frame", self.delay.as_millis())
+        } else if self.done && self.current + 1 == self.history.len() {
+            "finished".to_string()
+        } else {
+            "paused".to_string()
+        };
+
+        // Drawn over the previous frame and cleared after, which flickers
+        // less than clearing the whole screen first.
+        let mut screen = String::from("\x1b[H");
+        screen += &ansi(frame);
+        let _ = write!(screen, "\n{}\x1b[K\n{} | {}\x1b[K\x1b[J", frame.caption, state, HELP);
+        self.terminal.write(&screen);
+    }
+}
+
+impl Sink for Player {
+    fn push(&mut self, frame: Frame) -> bool {
+        if self.history.len() == HISTORY {
+            self.history.pop_front();
+        }
+        self.history.push_back(frame);
+        self.current = self.history.len() - 1;
+
+        self.show()
+    }
+}
+
+/// The frame's grid with each cell in its palette colour, and highlighted
+/// cells in reverse video.
+fn ansi(frame: &Frame) -> String {
+    let mut out = String::new();
+
+    for (y, row) in frame.grid.rows().enumerate() {
+        for (x, c) in row.iter().enumerate() {
+            let util::image::Rgb(r, g, b) = frame.palette.color(c);
+            let reverse = if frame.highlight.contains(&(x, y)) { ";7" } else { "" };
+            let _ = write!(out, "\x1b[0;38;2;{};{};{}{}m{}", r, g, b, reverse, c);
+        }
+        out += "\x1b[0m\n";
+    }
+
+    out
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Key {
+    Pause,
+    Forward,
+    Back,
+    Faster,
+    Slower,
+    Quit,
+}
+
+impl Key {
+    fn parse(bytes: &[u8]) -> Option<Key> {
+        match bytes {
+            b" " | b"p" => Some(Key::Pause),
+            b"\x1b[C" | b"l" | b"." => Some(Key::Forward),
+            b"\x1b[D" | b"h" | b"," => Some(Key::Back),
+            b"+" | b"=" => Some(Key::Faster),
+            b"-" => Some(Key::Slower),
+            b"q" | b"\x03" | b"\x1b" => Some(Key::Quit),
+            _ => None,
+        }
+    }
+}
+
+/// The terminal in a mode where keys are read as soon as they are pressed,
+/// without being echoed. Dropping it puts the terminal back as it was.
+struct Terminal {
+    settings: String,
+}
+
+impl Terminal {
+    fn open() -> Result<Terminal, String> {
+        let settings = stty(&["-g"]).map_err(|_| "play needs a terminal".to_string())?;
+        stty(&["-icanon", "-echo", "-isig", "min", "0", "time", "0"])
+            .map_err(|err| format!("failed to set up the terminal: {}", err))?;
+
+        // The alternate screen, with the cursor hidden.
+        let terminal = Terminal {
+            settings: settings.trim().to_string(),
+        };
+        terminal.write("\x1b[?1049h\x1b[?25l");
+        Ok(terminal)
+    }
+
+    fn write(&self, s: &str) {
+        let mut stdout = io::stdout().lock();
+        let _ = stdout.write_all(s.as_bytes());
+        let _ = stdout.flush();
+    }
+
+    /// The key pressed since the last call, if any.
+    fn key(&self) -> Option<Key> {
+        let mut buf = [0; 8];
+        let n = io::stdin().read(&mut buf).ok()?;
+        Key::parse(&buf[..n])
+    }
+}
+
+impl Drop for Terminal {
+    fn drop(&mut self) {
+        self.write("\x1b[?25h\x1b[?1049l");
+        let _ = stty(&[&self.settings]);
+    }
+}
+
+/// Runs `stty` on the terminal stdin is connected to.
+fn stty(args: &[&str]) -> Result<String, String> {
+    let output = Command::new("stty")
+        .args(args)
+        .stdin(Stdio::inherit())
+        .stderr(Stdio::piped())
+        .output()
+        .map_err(|err| err.to_string())?;
+
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use util::{image::Palette, image::Rgb, Grid};
+
+    use super::*;
+
+    #[test]
+    fn test_ansi() {
+        let frame = Frame {
+            grid: Grid::parse("#@").unwrap(),
+            palette: Palette::new(Rgb::BLACK).with('#', Rgb(1, 2, 3)),
+            highlight: vec![(1, 0)],
+            caption: String::new(),
+        };
+
+        assert_eq!(
+            ansi(&frame),
+            "\x1b[0;38;2;1;2;3m#\x1b[0;38;2;0;0;0;7m@\x1b[0m\n"
+        );
+    }
+
+    #[test]
+    fn test_keys() {
+        assert_eq!(Key::parse(b" "), Some(Key::Pause));
+        assert_eq!(Key::parse(b"\x1b[C"), Some(Key::Forward));
+        assert_eq!(Key::parse(b"\x1b[D"), Some(Key::Back));
+        assert_eq!(Key::parse(b"q"), Some(Key::Quit));
+        assert_eq!(Key::parse(b""), None);
+    }
+}
@@ -0,0 +1,89 @@
+use std::{
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+/// What became of one item: the result of the call, or the message it
+/// panicked with, and how long the call took.
+pub type Outcome<R> = (Result<R, String>, Duration);
+
+/// The number of threads to use when none is asked for.
+pub fn default_jobs() -> usize {
+    thread::available_parallelism().map_or(1, |n| n.get())
+}
+
+/// Calls `f` on every item on up to `jobs` threads, returning the outcomes
+/// in the order of `items`.
+///
+/// A panic in `f` doesn't affect the other items: it is caught and returned
+/// as the panic message. The default panic hook is silenced meanwhile, so
+/// that concurrent panics don't interleave their output with the results.
+pub fn map<T, R, F>(items: &[T], jobs: usize, f: F) -> Vec<Outcome<R>>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    let next = AtomicUsize::new(0);
+    let results: Mutex<Vec<Option<Outcome<R>>>> = Mutex::new(items.iter().map(|_| None).collect());
+
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, items.len().max(1)) {
+            scope.spawn(|| loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some(item) = items.get(i) else {
+                    break;
+                };
+
+                let start = Instant::now();
+                let result = panic::catch_unwind(AssertUnwindSafe(|| f(item))).map_err(|payload| {
+                    payload
+                        .downcast_ref::<&str>()
+                        .map(|s| s.to_string())
+                        .or_else(|| payload.downcast_ref::<String>().cloned())
+                        .unwrap_or_else(|| "unknown panic".to_string())
+                });
+                results.lock().unwrap()[i] = Some((result, start.elapsed()));
+            });
+        }
+    });
+
+    panic::set_hook(hook);
+
+    results
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|r| r.expect("every item has a result"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map() {
+        let items: Vec<u32> = (1..=20).collect();
+
+        let results = map(&items, 4, |&n| {
+            if n == 7 {
+                panic!("this is impossible!");
+            }
+            n * 2
+        });
+
+        assert_eq!(results.len(), 20);
+        assert_eq!(results[0].0, Ok(2));
+        assert_eq!(results[6].0, Err("this is impossible!".to_string()));
+        assert_eq!(results[19].0, Ok(40));
+    }
+}
@@ -0,0 +1,125 @@
+use std::{fs, str::FromStr, time::Duration};
+
+use util::record::Recorder;
+
+use crate::{days, Selection};
+
+/// Records the simulation behind one part of a day to an animated GIF.
+pub struct Record {
+    selection: Selection,
+    out: String,
+    skip: usize,
+    max_frames: Option<usize>,
+    scale: usize,
+    delay: Duration,
+}
+
+impl Record {
+    pub fn parse(args: &[String]) -> Result<Record, String> {
+        let mut out = None;
+        let mut skip = 0;
+        let mut max_frames = None;
+        let mut scale = 4;
+        let mut delay = Duration::from_millis(100);
+        let mut rest = Vec::new();
+
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--out" | "-o" => {
+                    out = Some(args.next().ok_or("missing path after --out")?.clone());
+                }
+                "--skip" => skip = number(args.next(), "--skip")?,
+                "--max-frames" => {
+                    max_frames = Some(number::<usize>(args.next(), "--max-frames")?.max(1));
+                }
+                "--scale" => scale = number::<usize>(args.next(), "--scale")?.max(1),
+                "--delay" | "-d" => delay = Duration::from_millis(number(args.next(), "--delay")?),
+                _ => rest.push(arg.clone()),
+            }
+        }
+
+        let out = out.ok_or("missing --out")?;
+        if !out.ends_with(".gif") {
+            return Err(format!("'{}' is not a .gif", out));
+        }
+
+        let selection = Selection::parse(&rest, false)?;
+        if selection.days.len() != 1 {
+            return Err("record takes a single day".to_string());
+        }
+
+        Ok(Record {
+            selection,
+            out,
+            skip,
+            max_frames,
+            scale,
+            delay,
+        })
+    }
+
+    pub fn run(&self) -> Result<(), String> {
+        let number = self.selection.days[0].number;
+        let animated = days::find_animated(number)
+            .ok_or(format!("day {} has no animation", number))?;
+
+        let path = util::input_path(number, self.selection.input.as_deref());
+        let input = util::read_input(&path).map_err(|err| err.to_string())?;
+
+        let mut recorder = Recorder::create(&self.out)
+            .map_err(|err| format!("{}: {}", self.out, err))?
+            .scale(self.scale)
+            .delay(self.delay)
+            .skip(self.skip);
+        if let Some(max_frames) = self.max_frames {
+            recorder = recorder.max_frames(max_frames);
+        }
+
+        // The input is only parsed once the file is open, so don't leave an
+        // empty or half-written file behind when that or recording fails.
+        let recorded = (animated.animate)(&input, self.selection.parts[0], &mut recorder)
+            .map_err(|err| format!("{}:{}", path.display(), err))
+            .and_then(|_| {
+                recorder
+                    .finish()
+                    .map_err(|err| format!("{}: {}", self.out, err))
+            });
+        let frames = recorded.inspect_err(|_| {
+            let _ = fs::remove_file(&self.out);
+        })?;
+
+        println!("Recorded {} frames to {}", frames, self.out);
+        Ok(())
+    }
+}
+
+fn number<T: FromStr>(value: Option<&String>, flag: &str) -> Result<T, String> {
+    let value = value.ok_or(format!("missing value after {}", flag))?;
+    value
+        .parse()
+        .map_err(|_| format!("invalid value '{}' for {}", value, flag))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    #[test]
+    fn test_bad_input_leaves_no_file() {
+        let dir = env::temp_dir();
+        let input = dir.join(format!("aoc-record-{}.txt", std::process::id()));
+        let out = dir.join(format!("aoc-record-{}.gif", std::process::id()));
+        fs::write(&input, "p=0,4 v=3,-3\np=6,3 w=-1,-3\n").unwrap();
+
+        let args = ["14", "--input", input.to_str().unwrap(), "--out", out.to_str().unwrap()];
+        let record = Record::parse(&args.map(String::from)).unwrap();
+        let err = record.run().unwrap_err();
+
+        fs::remove_file(&input).unwrap();
+        assert!(err.ends_with("2:7: expected 'v=', found 'w=-1,-3'"), "{}", err);
+        assert!(!out.exists());
+    }
+}
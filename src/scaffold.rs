@@ -0,0 +1,365 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+const TEMPLATE: &str = "dayXX";
+
+/// Files copied from the template, relative to the day's directory.
+const FILES: &[&str] = &["Cargo.toml", "input.txt", "src/lib.rs", "src/main.rs"];
+
+/// The fuzz target for a day's parser, rendered like the template.
+const FUZZ_TARGET: &str = "#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use util::Solution;
+
+fuzz_target!(|data: &str| {
+    let _ = dayXX::DayXX::parse(data);
+});
+";
+
+/// Creates `dayN` from the `dayXX` template in a workspace and registers it
+/// with the runner, the generate crate's tests and the fuzz targets.
+pub struct NewDay {
+    day: u8,
+    example: Option<String>,
+    /// The workspace root, the current directory by default.
+    root: PathBuf,
+}
+
+impl NewDay {
+    pub fn parse(args: &[String]) -> Result<NewDay, String> {
+        let mut day = None;
+        let mut example = None;
+        let mut root = PathBuf::from(".");
+
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--example" => {
+                    let path = args.next().ok_or("missing path after --example")?;
+                    example = Some(path.clone());
+                }
+                "--root" => {
+                    root = args.next().ok_or("missing directory after --root")?.into();
+                }
+                s if day.is_none() => {
+                    let number = s.parse().map_err(|_| format!("invalid day '{}'", s))?;
+                    if !(1..=25).contains(&number) {
+                        return Err(format!("invalid day '{}'", s));
+                    }
+                    day = Some(number);
+                }
+                s => return Err(format!("unexpected argument '{}'", s)),
+            }
+        }
+
+        Ok(NewDay {
+            day: day.ok_or("missing day")?,
+            example,
+            root,
+        })
+    }
+
+    pub fn run(&self) -> Result<(), String> {
+        let name = format!("day{}", self.day);
+        let dir = self.root.join(&name);
+        if dir.exists() {
+            return Err(format!("'{}' already exists", dir.display()));
+        }
+
+        let example = match &self.example {
+            Some(path) => Some(util::read_input(path).map_err(|err| err.to_string())?),
+            None => None,
+        };
+
+        // Render everything up front so that a bad template leaves nothing
+        // half-written behind.
+        let mut files = Vec::new();
+        for file in FILES {
+            let template = self.root.join(TEMPLATE).join(file);
+            let text = read(&template)?;
+
+            let mut text = render(&text, self.day);
+            if *file == "src/lib.rs" {
+                if let Some(example) = &example {
+                    text = insert_example(&text, example)?;
+                }
+            }
+            files.push((dir.join(file), text));
+        }
+
+        files.push((
+            self.root.join("fuzz/fuzz_targets").join(format!("{}.rs", name)),
+            render(FUZZ_TARGET, self.day),
+        ));
+
+        let register = |path: &Path, section: &str, dir: &str| {
+            register_dependency(&read(path)?, section, self.day, dir)
+                .map_err(|err| format!("{}: {}", path.display(), err))
+        };
+        let root_manifest = self.root.join("Cargo.toml");
+        let days_path = self.root.join("src/days.rs");
+        let generate_manifest = self.root.join("generate/Cargo.toml");
+        let fuzz_manifest = self.root.join("fuzz/Cargo.toml");
+
+        let manifest = register(&root_manifest, "[dependencies]", "")?;
+        let days = register_day(&read(&days_path)?, self.day)
+            .map_err(|err| format!("{}: {}", days_path.display(), err))?;
+        let generate = register(&generate_manifest, "[dev-dependencies]", "../")?;
+        let fuzz = register(&fuzz_manifest, "[dependencies]", "../")?;
+        let fuzz = register_fuzz_target(&fuzz, self.day);
+
+        for (path, text) in files {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent).map_err(|err| write_error(parent, err))?;
+            }
+            write(&path, &text)?;
+        }
+        write(&root_manifest, &manifest)?;
+        write(&days_path, &days)?;
+        write(&generate_manifest, &generate)?;
+        write(&fuzz_manifest, &fuzz)?;
+
+        println!("Created {} and registered it with the runner and the fuzz targets", dir.display());
+        println!(
+            "Still to do: add a generator for {} to generate::GENERATORS, and solve its inputs \
+             in generate/tests/inputs.rs",
+            name
+        );
+
+        Ok(())
+    }
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|err| format!("failed to read '{}': {}", path.display(), err))
+}
+
+fn write(path: &Path, text: &str) -> Result<(), String> {
+    fs::write(path, text).map_err(|err| write_error(path, err))
+}
+
+fn write_error(path: &Path, err: io::Error) -> String {
+    format!("failed to write '{}': {}", path.display(), err)
+}
+
+/// Fills in the day number for the template's placeholders.
+fn render(template: &str, day: u8) -> String {
+    template
+        .replace("#![allow(non_snake_case)]\n\n", "")
+        .replace(">(0)", &format!(">({})", day))
+        .replace(TEMPLATE, &format!("day{}", day))
+        .replace("DayXX", &format!("Day{}", day))
+}
+
+/// Replaces the placeholder in the `INPUT` test constant with `example`.
+fn insert_example(lib: &str, example: &str) -> Result<String, String> {
+    const PLACEHOLDER: &str = "    const INPUT: &str = \"\n        Something\n    \";\n";
+
+    if !lib.contains(PLACEHOLDER) {
+        return Err("the template has no INPUT placeholder to replace".to_string());
+    }
+
+    let mut body = String::new();
+    for line in example.trim_matches('\n').lines() {
+        let line = line.trim_end().replace('\\', "\\\\").replace('"', "\\\"");
+        if !line.is_empty() {
+            body.push_str("        ");
+        }
+        body.push_str(&line);
+        body.push('\n');
+    }
+
+    Ok(lib.replacen(PLACEHOLDER, &format!("    const INPUT: &str = \"\n{}    \";\n", body), 1))
+}
+
+/// Adds `dayN = { path = "dayN" }` to a manifest's `section`, in day
+/// order, with the path starting with `dir` for manifests that aren't at
+/// the root.
+fn register_dependency(manifest: &str, section: &str, day: u8, dir: &str) -> Result<String, String> {
+    let line = format!("day{0} = {{ path = \"{1}day{0}\" }}", day, dir);
+
+    insert_sorted(manifest, &line, section, day, |l| {
+        let (name, _) = l.split_once(" = { path = ")?;
+        name.strip_prefix("day")?.parse().ok()
+    })
+}
+
+/// Adds the day's fuzz target to the end of the fuzz manifest.
+fn register_fuzz_target(manifest: &str, day: u8) -> String {
+    format!(
+        "{}\n\n[[bin]]\nname = \"day{1}\"\npath = \"fuzz_targets/day{1}.rs\"\ntest = false\ndoc = false\nbench = false\n",
+        manifest.trim_end(),
+        day
+    )
+}
+
+/// Adds the day to the runner's `DAYS` table, in day order.
+fn register_day(days: &str, day: u8) -> Result<String, String> {
+    let line = format!("    Day {{ number: {0}, solve: solve::<day{0}::Day{0}> }},", day);
+
+    insert_sorted(days, &line, "pub const DAYS: &[Day] = &[", day, |l| {
+        let rest = l.trim().strip_prefix("Day { number: ")?;
+        let (number, _) = rest.split_once(',')?;
+        number.parse().ok()
+    })
+}
+
+/// Inserts `new` among the consecutive lines that `day_of` recognizes,
+/// keeping them ordered by day, or right after the `header` line if there
+/// are none yet, as in a workspace for a new year. Fails if the day is
+/// already there, or if neither the days nor the header are found.
+fn insert_sorted<F>(text: &str, new: &str, header: &str, day: u8, day_of: F) -> Result<String, String>
+where
+    F: Fn(&str) -> Option<u8>,
+{
+    let lines: Vec<&str> = text.lines().collect();
+
+    let at = match lines.iter().position(|l| day_of(l).is_some()) {
+        Some(first) => {
+            let mut at = first;
+            for (i, l) in lines.iter().enumerate().skip(first) {
+                match day_of(l) {
+                    Some(d) if d == day => return Err(format!("day {} is already registered", day)),
+                    Some(d) if d < day && at == i => at = i + 1,
+                    Some(_) => {}
+                    None => break,
+                }
+            }
+            at
+        }
+        None => {
+            let header = lines
+                .iter()
+                .position(|l| l.trim() == header)
+                .ok_or_else(|| format!("no days or '{}' found", header))?;
+            header + 1
+        }
+    };
+
+    let mut result: Vec<&str> = lines[..at].to_vec();
+    result.push(new);
+    result.extend_from_slice(&lines[at..]);
+
+    let mut result = result.join("\n");
+    if text.ends_with('\n') {
+        result.push('\n');
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let main = render("fn main() {\n    util::run::<dayXX::DayXX>(0);\n}\n", 7);
+        assert_eq!(main, "fn main() {\n    util::run::<day7::Day7>(7);\n}\n");
+
+        let lib = render("#![allow(non_snake_case)]\n\nuse util::Solution;\n\npub struct DayXX;\n", 7);
+        assert_eq!(lib, "use util::Solution;\n\npub struct Day7;\n");
+    }
+
+    #[test]
+    fn test_insert_example() {
+        let lib = "mod tests {\n    const INPUT: &str = \"\n        Something\n    \";\n}\n";
+
+        let lib = insert_example(lib, "\n1 \"2\"\n\n3\n").unwrap();
+
+        assert_eq!(
+            lib,
+            "mod tests {\n    const INPUT: &str = \"\n        1 \\\"2\\\"\n\n        3\n    \";\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_register() {
+        let manifest = "[dependencies]\nday1 = { path = \"day1\" }\nday3 = { path = \"day3\" }\nutil = { path = \"util\" }\n";
+        assert_eq!(
+            register_dependency(manifest, "[dependencies]", 2, "").unwrap(),
+            "[dependencies]\nday1 = { path = \"day1\" }\nday2 = { path = \"day2\" }\nday3 = { path = \"day3\" }\nutil = { path = \"util\" }\n"
+        );
+
+        let manifest = "[dependencies]\nday1 = { path = \"../day1\" }\n";
+        assert_eq!(
+            register_dependency(manifest, "[dependencies]", 2, "../").unwrap(),
+            "[dependencies]\nday1 = { path = \"../day1\" }\nday2 = { path = \"../day2\" }\n"
+        );
+        assert_eq!(
+            register_dependency(manifest, "[dependencies]", 1, "../"),
+            Err("day 1 is already registered".to_string())
+        );
+
+        let manifest = "[dev-dependencies]\nrand = \"0.8.5\"\n";
+        assert_eq!(
+            register_dependency(manifest, "[dev-dependencies]", 2, "../").unwrap(),
+            "[dev-dependencies]\nday2 = { path = \"../day2\" }\nrand = \"0.8.5\"\n"
+        );
+        assert_eq!(
+            register_dependency("[package]\n", "[dependencies]", 2, ""),
+            Err("no days or '[dependencies]' found".to_string())
+        );
+
+        let days = "pub const DAYS: &[Day] = &[\n    Day { number: 1, solve: solve::<day1::Day1> },\n];\n";
+        assert_eq!(
+            register_day(days, 26).unwrap(),
+            "pub const DAYS: &[Day] = &[\n    Day { number: 1, solve: solve::<day1::Day1> },\n    Day { number: 26, solve: solve::<day26::Day26> },\n];\n"
+        );
+    }
+
+    #[test]
+    fn test_new_workspace() {
+        let root = env::temp_dir().join(format!("aoc-new-day-{}", std::process::id()));
+        let files = [
+            ("Cargo.toml", "[workspace]\nmembers = [\"day*\"]\n\n[dependencies]\n"),
+            ("src/days.rs", "pub const DAYS: &[Day] = &[\n];\n"),
+            ("generate/Cargo.toml", "[dev-dependencies]\n"),
+            ("fuzz/Cargo.toml", "[dependencies]\nlibfuzzer-sys = \"0.4.9\"\n"),
+        ];
+        for (file, text) in files {
+            fs::create_dir_all(root.join(file).parent().unwrap()).unwrap();
+            fs::write(root.join(file), text).unwrap();
+        }
+        for file in FILES {
+            let template = root.join(TEMPLATE).join(file);
+            fs::create_dir_all(template.parent().unwrap()).unwrap();
+            fs::copy(Path::new(TEMPLATE).join(file), template).unwrap();
+        }
+
+        let args = ["3", "--root", root.to_str().unwrap()].map(String::from);
+        NewDay::parse(&args).unwrap().run().unwrap();
+
+        let read = |file| fs::read_to_string(root.join(file)).unwrap();
+        assert!(read("day3/src/main.rs").contains("util::run::<day3::Day3>(3);"));
+        assert!(read("fuzz/fuzz_targets/day3.rs").contains("day3::Day3::parse(data)"));
+        assert_eq!(read("Cargo.toml"), format!("{}day3 = {{ path = \"day3\" }}\n", files[0].1));
+        assert_eq!(
+            read("src/days.rs"),
+            "pub const DAYS: &[Day] = &[\n    Day { number: 3, solve: solve::<day3::Day3> },\n];\n"
+        );
+        assert_eq!(read("generate/Cargo.toml"), "[dev-dependencies]\nday3 = { path = \"../day3\" }\n");
+
+        // A day can only be added once, even if its directory is gone.
+        fs::remove_dir_all(root.join("day3")).unwrap();
+        let err = NewDay::parse(&args).unwrap().run().unwrap_err();
+
+        fs::remove_dir_all(&root).unwrap();
+        assert_eq!(err, format!("{}: day 3 is already registered", root.join("Cargo.toml").display()));
+    }
+
+    #[test]
+    fn test_fuzz_target() {
+        assert!(render(FUZZ_TARGET, 7).contains("let _ = day7::Day7::parse(data);"));
+
+        let manifest = "[[bin]]\nname = \"day1\"\npath = \"fuzz_targets/day1.rs\"\ntest = false\ndoc = false\nbench = false\n";
+        assert_eq!(
+            register_fuzz_target(manifest, 2),
+            format!("{}\n{}", manifest, manifest.replace("day1", "day2"))
+        );
+    }
+}
@@ -0,0 +1,567 @@
+use std::{
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use util::{ParseError, Source};
+
+use crate::Selection;
+
+/// Name of the record of submitted answers, kept in the cache directory.
+const RECORD_FILE: &str = "submissions.txt";
+
+/// How long the site makes you wait after a wrong answer if the page
+/// doesn't say.
+const WRONG_ANSWER_WAIT: Duration = Duration::from_secs(60);
+
+/// Submits an answer to one part of a day, computing it if not given.
+pub struct Submit {
+    selection: Selection,
+    answer: Option<String>,
+}
+
+impl Submit {
+    pub fn parse(args: &[String]) -> Result<Submit, String> {
+        let mut rest = Vec::new();
+        let mut positional = 0;
+        let mut answer = None;
+
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--input" | "-i" => {
+                    rest.push(arg.clone());
+                    rest.extend(args.next().cloned());
+                }
+                _ if positional == 2 && answer.is_none() => answer = Some(arg.clone()),
+                _ => {
+                    positional += 1;
+                    rest.push(arg.clone());
+                }
+            }
+        }
+
+        let selection = Selection::parse(&rest, false)?;
+        if selection.days.len() != 1 {
+            return Err("submit takes a single day".to_string());
+        }
+        if selection.parts.len() != 1 {
+            return Err("missing part".to_string());
+        }
+
+        Ok(Submit { selection, answer })
+    }
+
+    /// Returns whether the answer is known to be correct.
+    pub fn run(&self) -> Result<bool, String> {
+        let day = self.selection.days[0];
+        let part = self.selection.parts[0];
+
+        let answer = match &self.answer {
+            Some(answer) => answer.clone(),
+            None => {
+                let report = self.selection.solve(day)?;
+                report.parts[0].answer.clone()
+            }
+        };
+
+        let session = util::session()
+            .ok_or_else(|| format!("${} must be set to submit answers", util::SESSION_VAR))?;
+        let submitter = Submitter {
+            client: Client {
+                base_url: util::base_url(),
+                session,
+            },
+            record_path: util::cache_dir().join(RECORD_FILE),
+        };
+
+        let outcome = submitter.submit(day.number, part, &answer, SystemTime::now())?;
+        println!("Day {} part {}: {}", day.number, part, answer);
+        println!("{}", outcome);
+
+        Ok(outcome.is_correct())
+    }
+}
+
+/// What the site said about a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// The answer was sent too soon after the previous one and not checked.
+    Wait(Duration),
+    /// The part has already been solved on the site.
+    AlreadySolved,
+    /// A page that couldn't be made sense of, as text.
+    Unknown(String),
+}
+
+impl Verdict {
+    /// Reads the verdict from the page returned for a submission.
+    pub fn parse(html: &str) -> Verdict {
+        let text = article_text(html);
+
+        if text.contains("That's the right answer") {
+            Verdict::Correct
+        } else if text.contains("You gave an answer too recently") {
+            Verdict::Wait(parse_wait(&text).unwrap_or(WRONG_ANSWER_WAIT))
+        } else if text.contains("You don't seem to be solving the right level") {
+            Verdict::AlreadySolved
+        } else if text.contains("That's not the right answer") {
+            if text.contains("your answer is too high") {
+                Verdict::TooHigh
+            } else if text.contains("your answer is too low") {
+                Verdict::TooLow
+            } else {
+                Verdict::Wrong
+            }
+        } else {
+            Verdict::Unknown(text)
+        }
+    }
+
+    /// The name used in the record, for the verdicts that are kept.
+    fn name(&self) -> Option<&'static str> {
+        match self {
+            Verdict::Correct => Some("correct"),
+            Verdict::TooHigh => Some("too-high"),
+            Verdict::TooLow => Some("too-low"),
+            Verdict::Wrong => Some("wrong"),
+            _ => None,
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Verdict> {
+        [
+            Verdict::Correct,
+            Verdict::TooHigh,
+            Verdict::TooLow,
+            Verdict::Wrong,
+        ]
+        .into_iter()
+        .find(|v| v.name() == Some(name))
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::Wait(wait) => write!(f, "not checked, wait {}s", wait.as_secs()),
+            Verdict::AlreadySolved => write!(f, "already solved"),
+            Verdict::Unknown(text) => write!(f, "unknown response: {}", text),
+        }
+    }
+}
+
+/// The text of the page's `<article>`, without tags and with whitespace
+/// collapsed.
+fn article_text(html: &str) -> String {
+    let body = match (html.find("<article"), html.find("</article>")) {
+        (Some(start), Some(end)) if start < end => &html[start..end],
+        _ => html,
+    };
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in body.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Reads a wait time like "You have 1m 30s left to wait" or "Please wait
+/// one minute before trying again".
+fn parse_wait(text: &str) -> Option<Duration> {
+    if let Some(i) = text.find("You have ") {
+        let rest = &text[i + "You have ".len()..];
+        let end = rest.find(" left to wait")?;
+
+        let mut secs = 0;
+        for token in rest[..end].split_whitespace() {
+            let (n, unit) = token.split_at(token.len() - 1);
+            let unit = match unit {
+                "h" => 3600,
+                "m" => 60,
+                "s" => 1,
+                _ => return None,
+            };
+            secs += n.parse::<u64>().ok()? * unit;
+        }
+        return Some(Duration::from_secs(secs));
+    }
+
+    const NUMBERS: [&str; 10] = [
+        "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten",
+    ];
+
+    let text = text.to_lowercase();
+    let i = text.find("please wait ")?;
+    let mut words = text[i + "please wait ".len()..].split_whitespace();
+    let n = words.next()?;
+    let n = match NUMBERS.iter().position(|&w| w == n) {
+        Some(i) => i as u64 + 1,
+        None => n.parse().ok()?,
+    };
+    match words.next()? {
+        unit if unit.starts_with("minute") => Some(Duration::from_secs(n * 60)),
+        unit if unit.starts_with("second") => Some(Duration::from_secs(n)),
+        _ => None,
+    }
+}
+
+/// The result of trying to submit an answer.
+#[derive(Debug, PartialEq, Eq)]
+enum Outcome {
+    /// The answer was sent, and this is what the site said.
+    Sent(Verdict),
+    /// The same answer was sent before.
+    Known(Verdict),
+    /// An earlier answer that was too high or too low rules this one out.
+    Bound(Verdict, String),
+    /// The part was solved with another answer.
+    Solved(String),
+    /// Nothing is sent until the cooldown from the previous answer is over.
+    Cooldown(Duration),
+}
+
+impl Outcome {
+    fn is_correct(&self) -> bool {
+        matches!(
+            self,
+            Outcome::Sent(Verdict::Correct) | Outcome::Known(Verdict::Correct)
+        )
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Sent(verdict) => write!(f, "{}", verdict),
+            Outcome::Known(verdict) => write!(f, "{} (submitted before, not sent again)", verdict),
+            Outcome::Bound(verdict, answer) => {
+                write!(
+                    f,
+                    "{} (not sent, {} was already {})",
+                    verdict, answer, verdict
+                )
+            }
+            Outcome::Solved(answer) => {
+                write!(f, "wrong (not sent, the correct answer is {})", answer)
+            }
+            Outcome::Cooldown(wait) => write!(f, "not sent, wait {}s", wait.as_secs()),
+        }
+    }
+}
+
+struct Client {
+    base_url: String,
+    session: String,
+}
+
+impl Client {
+    /// Posts the answer and returns the page the site responds with.
+    fn post(&self, day: u8, part: u8, answer: &str) -> Result<String, String> {
+        let url = format!(
+            "{}/{}/day/{}/answer",
+            self.base_url.trim_end_matches('/'),
+            util::YEAR,
+            day
+        );
+        let failed = |err: &dyn Display| format!("failed to submit to '{}': {}", url, err);
+
+        let agent = ureq::AgentBuilder::new()
+            .timeout(Duration::from_secs(30))
+            .user_agent(util::USER_AGENT)
+            .build();
+        agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])
+            .map_err(|err| failed(&err))?
+            .into_string()
+            .map_err(|err| failed(&err))
+    }
+}
+
+struct Submitter {
+    client: Client,
+    record_path: PathBuf,
+}
+
+impl Submitter {
+    /// Sends `answer` unless the record already tells how it would go, and
+    /// records the verdict.
+    fn submit(&self, day: u8, part: u8, answer: &str, now: SystemTime) -> Result<Outcome, String> {
+        let now = now.duration_since(UNIX_EPOCH).unwrap_or_default();
+        let mut record = Record::load(&self.record_path)?;
+
+        if let Some(outcome) = record.check(day, part, answer, now) {
+            return Ok(outcome);
+        }
+
+        let page = self.client.post(day, part, answer)?;
+        let verdict = Verdict::parse(&page);
+
+        // The wait after a wrong answer grows with each one, and the page
+        // says how long it is.
+        let wait = match &verdict {
+            Verdict::Wait(wait) => *wait,
+            Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong => {
+                parse_wait(&article_text(&page)).unwrap_or(WRONG_ANSWER_WAIT)
+            }
+            _ => Duration::ZERO,
+        };
+        record.next = record.next.max(now + wait);
+        if verdict.name().is_some() {
+            record
+                .entries
+                .push((day, part, verdict.clone(), answer.to_string()));
+        }
+        record.save(&self.record_path)?;
+
+        Ok(Outcome::Sent(verdict))
+    }
+}
+
+/// Every answer submitted so far with its verdict, and the earliest time
+/// another one may be sent.
+///
+/// Stored as one `DAY PART VERDICT ANSWER` line per answer, plus a
+/// `next SECONDS` line with the time since the Unix epoch.
+#[derive(Debug, Default)]
+struct Record {
+    entries: Vec<(u8, u8, Verdict, String)>,
+    next: Duration,
+}
+
+impl Record {
+    fn load(path: &Path) -> Result<Record, String> {
+        match fs::read_to_string(path) {
+            Ok(data) => Record::parse(&data).map_err(|err| format!("{}:{}", path.display(), err)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Record::default()),
+            Err(err) => Err(format!("failed to read '{}': {}", path.display(), err)),
+        }
+    }
+
+    fn parse(data: &str) -> Result<Record, ParseError> {
+        let source = Source::new(data);
+        let mut record = Record::default();
+
+        for line in source.lines().filter(|l| !l.starts_with('#')) {
+            if let Some(secs) = line.strip_prefix("next ") {
+                record.next = Duration::from_secs(source.parse(secs, "a time in seconds")?);
+                continue;
+            }
+
+            let mut fields = line.splitn(4, ' ');
+            let day = source.next(&mut fields, line, "a day")?;
+            let part = source.next(&mut fields, day, "a part")?;
+            let verdict = source.next(&mut fields, part, "a verdict")?;
+            let answer = source.next(&mut fields, verdict, "an answer")?;
+
+            record.entries.push((
+                source.parse(day, "a day")?,
+                source.parse(part, "a part")?,
+                Verdict::from_name(verdict).ok_or_else(|| source.error(verdict, "a verdict"))?,
+                answer.to_string(),
+            ));
+        }
+
+        Ok(record)
+    }
+
+    fn save(&self, path: &Path) -> Result<(), String> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)
+                .map_err(|err| format!("failed to create '{}': {}", dir.display(), err))?;
+        }
+        fs::write(path, self.to_string())
+            .map_err(|err| format!("failed to write '{}': {}", path.display(), err))
+    }
+
+    /// What is already known about `answer`, if anything.
+    fn check(&self, day: u8, part: u8, answer: &str, now: Duration) -> Option<Outcome> {
+        let entries = self.entries.iter().filter(|e| e.0 == day && e.1 == part);
+        let number = answer.parse::<i64>().ok();
+
+        for (_, _, verdict, previous) in entries {
+            if previous == answer {
+                return Some(Outcome::Known(verdict.clone()));
+            }
+
+            let bound = previous.parse::<i64>().ok().zip(number);
+            match (verdict, bound) {
+                (Verdict::Correct, _) => return Some(Outcome::Solved(previous.clone())),
+                (Verdict::TooHigh, Some((high, n))) if n >= high => {
+                    return Some(Outcome::Bound(Verdict::TooHigh, previous.clone()))
+                }
+                (Verdict::TooLow, Some((low, n))) if n <= low => {
+                    return Some(Outcome::Bound(Verdict::TooLow, previous.clone()))
+                }
+                _ => {}
+            }
+        }
+
+        (now < self.next).then(|| Outcome::Cooldown(self.next - now))
+    }
+}
+
+impl Display for Record {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "# day part verdict answer")?;
+        writeln!(f, "next {}", self.next.as_secs())?;
+        for (day, part, verdict, answer) in self.entries.iter() {
+            writeln!(
+                f,
+                "{} {} {} {}",
+                day,
+                part,
+                verdict.name().unwrap_or("wrong"),
+                answer
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use util::mock;
+
+    use super::*;
+
+    const TOO_HIGH: &str =
+        "<main><article><p>That's not the right answer; your answer is too high. \
+        If you're stuck, make sure you're using the full input data. Please wait one minute before \
+        trying again. <a href=\"/2024/day/7\">[Return to Day 7]</a></p></article></main>";
+
+    fn submitter(url: String, name: &str) -> Submitter {
+        let path = env::temp_dir().join(format!("aoc-submit-{}-{}.txt", name, std::process::id()));
+        let _ = fs::remove_file(&path);
+
+        Submitter {
+            client: Client {
+                base_url: url,
+                session: "abc".to_string(),
+            },
+            record_path: path,
+        }
+    }
+
+    #[test]
+    fn test_verdict() {
+        assert_eq!(Verdict::parse(TOO_HIGH), Verdict::TooHigh);
+        assert_eq!(
+            Verdict::parse("<article><p>That's the right answer! You are <em>one gold star</em> closer.</p></article>"),
+            Verdict::Correct
+        );
+        assert_eq!(
+            Verdict::parse(
+                "<article><p>You gave an answer too recently; you have to wait after submitting an \
+                 answer before trying again.  You have 1m 5s left to wait.</p></article>"
+            ),
+            Verdict::Wait(Duration::from_secs(65))
+        );
+        assert_eq!(
+            Verdict::parse(
+                "<article><p>You don't seem to be solving the right level.</p></article>"
+            ),
+            Verdict::AlreadySolved
+        );
+        assert_eq!(
+            parse_wait("Please wait 5 minutes before"),
+            Some(Duration::from_secs(300))
+        );
+    }
+
+    #[test]
+    fn test_record_round_trip() {
+        let record = Record {
+            entries: vec![
+                (17, 1, Verdict::TooLow, "1,6,3".to_string()),
+                (17, 2, Verdict::Correct, "42".to_string()),
+            ],
+            next: Duration::from_secs(1733000000),
+        };
+
+        let parsed = Record::parse(&record.to_string()).unwrap();
+
+        assert_eq!(parsed.entries, record.entries);
+        assert_eq!(parsed.next, record.next);
+    }
+
+    #[test]
+    fn test_wrong_answer_is_sent_once() {
+        let (url, requests) = mock::serve(200, TOO_HIGH);
+        let submitter = submitter(url, "once");
+        let start = UNIX_EPOCH + Duration::from_secs(1733000000);
+        let later = start + Duration::from_secs(61);
+
+        assert_eq!(
+            submitter.submit(7, 2, "100", start).unwrap(),
+            Outcome::Sent(Verdict::TooHigh)
+        );
+        assert_eq!(
+            submitter
+                .submit(7, 2, "90", start + Duration::from_secs(10))
+                .unwrap(),
+            Outcome::Cooldown(Duration::from_secs(50))
+        );
+        assert_eq!(
+            submitter.submit(7, 2, "100", later).unwrap(),
+            Outcome::Known(Verdict::TooHigh)
+        );
+        assert_eq!(
+            submitter.submit(7, 2, "120", later).unwrap(),
+            Outcome::Bound(Verdict::TooHigh, "100".to_string())
+        );
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].line, "POST /2024/day/7/answer HTTP/1.1");
+        assert_eq!(requests[0].body, "level=2&answer=100");
+
+        fs::remove_file(&submitter.record_path).unwrap();
+    }
+
+    #[test]
+    fn test_wrong_answer_wait() {
+        let (url, _) = mock::serve(
+            200,
+            "<main><article><p>That's not the right answer. If you're stuck, make sure you're \
+            using the full input data. Please wait 5 minutes before trying again.</p></article></main>",
+        );
+        let submitter = submitter(url, "wait");
+        let start = UNIX_EPOCH + Duration::from_secs(1733000000);
+
+        assert_eq!(
+            submitter.submit(7, 1, "100", start).unwrap(),
+            Outcome::Sent(Verdict::Wrong)
+        );
+        assert_eq!(
+            submitter
+                .submit(7, 1, "90", start + Duration::from_secs(60))
+                .unwrap(),
+            Outcome::Cooldown(Duration::from_secs(240))
+        );
+
+        fs::remove_file(&submitter.record_path).unwrap();
+    }
+}
@@ -0,0 +1,158 @@
+use std::{path::PathBuf, process::exit};
+
+use crate::{answers::Answers, pool, Selection};
+
+pub struct Verify {
+    selection: Selection,
+    answers: PathBuf,
+}
+
+impl Verify {
+    pub fn parse(args: &[String]) -> Result<Verify, String> {
+        let mut answers = None;
+        let mut rest = Vec::new();
+
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--answers" => {
+                    let path = args.next().ok_or("missing path after --answers")?;
+                    answers = Some(PathBuf::from(path));
+                }
+                _ => rest.push(arg.clone()),
+            }
+        }
+
+        Ok(Verify {
+            selection: Selection::parse(&rest, true)?,
+            answers: answers.unwrap_or_else(Answers::default_path),
+        })
+    }
+
+    /// Checks every selected answer and returns the exit status.
+    pub fn run(&self) -> i32 {
+        let answers = Answers::load(&self.answers).unwrap_or_else(|err| {
+            eprintln!("error: {}", err);
+            exit(1);
+        });
+
+        // A day that panics is reported as an error like any other, rather
+        // than ending the run.
+        let results = pool::map(&self.selection.days, pool::default_jobs(), |day| {
+            self.selection.solve(day)
+        });
+
+        let mut rows = Vec::new();
+        for (day, (result, _)) in self.selection.days.iter().zip(results) {
+            let answer = |part| answers.get(day.number, part).unwrap_or("").to_string();
+
+            match result.unwrap_or_else(|msg| Err(format!("panicked: {}", msg))) {
+                Ok(report) => {
+                    for part in report.parts {
+                        let expected = answer(part.part);
+                        let status = if expected.is_empty() {
+                            Status::Unknown
+                        } else if expected == part.answer {
+                            Status::Pass
+                        } else {
+                            Status::Fail
+                        };
+
+                        rows.push(Check { day: day.number, part: part.part, expected, actual: part.answer, status });
+                    }
+                }
+                Err(err) => {
+                    for &part in self.selection.parts.iter() {
+                        rows.push(Check {
+                            day: day.number,
+                            part,
+                            expected: answer(part),
+                            actual: err.clone(),
+                            status: Status::Error,
+                        });
+                    }
+                }
+            }
+        }
+
+        print_checks(&rows);
+
+        let failed = rows.iter().filter(|r| matches!(r.status, Status::Fail | Status::Error)).count();
+        if failed > 0 {
+            1
+        } else {
+            0
+        }
+    }
+}
+
+enum Status {
+    Pass,
+    Fail,
+    Error,
+    Unknown,
+}
+
+impl Status {
+    fn label(&self) -> &'static str {
+        match self {
+            Status::Pass => "pass",
+            Status::Fail => "FAIL",
+            Status::Error => "ERROR",
+            Status::Unknown => "unknown",
+        }
+    }
+}
+
+struct Check {
+    day: u8,
+    part: u8,
+    expected: String,
+    actual: String,
+    status: Status,
+}
+
+fn print_checks(rows: &[Check]) {
+    let expected_width = rows
+        .iter()
+        .map(|r| r.expected.chars().count())
+        .chain(["Expected".len()])
+        .max()
+        .unwrap_or(0);
+    let actual_width = rows
+        .iter()
+        .map(|r| r.actual.chars().count())
+        .chain(["Actual".len()])
+        .max()
+        .unwrap_or(0);
+
+    println!("{:>3}  {:>4}  {:<expected_width$}  {:<actual_width$}  Result", "Day", "Part", "Expected", "Actual");
+    println!(
+        "{:>3}  {:>4}  {:<expected_width$}  {:<actual_width$}  -------",
+        "---",
+        "----",
+        "-".repeat(expected_width),
+        "-".repeat(actual_width),
+    );
+
+    for r in rows {
+        println!(
+            "{:>3}  {:>4}  {:<expected_width$}  {:<actual_width$}  {}",
+            r.day,
+            r.part,
+            r.expected,
+            r.actual,
+            r.status.label()
+        );
+    }
+
+    let count = |status: fn(&Status) -> bool| rows.iter().filter(|r| status(&r.status)).count();
+    println!();
+    println!(
+        "{} passed, {} failed, {} errors, {} without a known answer",
+        count(|s| matches!(s, Status::Pass)),
+        count(|s| matches!(s, Status::Fail)),
+        count(|s| matches!(s, Status::Error)),
+        count(|s| matches!(s, Status::Unknown)),
+    );
+}
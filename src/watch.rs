@@ -0,0 +1,375 @@
+use std::{
+    env,
+    ffi::{OsStr, OsString},
+    fs,
+    path::{Path, PathBuf},
+    process::{exit, Command, Output},
+    thread,
+    time::Duration,
+};
+
+use inotify::{Event, EventMask, Inotify, WatchDescriptor, WatchMask};
+
+/// How long to wait for more events after the first, so that an editor
+/// saving several files (or writing one in several steps) causes one run.
+const SETTLE: Duration = Duration::from_millis(200);
+
+/// Rebuilds and reruns a day, and its tests, whenever its sources or its
+/// input change.
+pub struct Watch {
+    day: u8,
+    input: Option<String>,
+}
+
+impl Watch {
+    pub fn parse(args: &[String]) -> Result<Watch, String> {
+        let mut day = None;
+        let mut input = None;
+
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--input" | "-i" => {
+                    let path = args.next().ok_or("missing path after --input")?;
+                    input = Some(path.clone());
+                }
+                s if day.is_none() => {
+                    day = Some(s.parse().map_err(|_| format!("invalid day '{}'", s))?)
+                }
+                s => return Err(format!("unexpected argument '{}'", s)),
+            }
+        }
+
+        Ok(Watch {
+            day: day.ok_or("missing day")?,
+            input,
+        })
+    }
+
+    pub fn run(&self) -> Result<(), String> {
+        let name = format!("day{}", self.day);
+        let dir = PathBuf::from(&name);
+        if !dir.is_dir() {
+            return Err(format!(
+                "'{}' doesn't exist, watch must be run from the workspace root",
+                name
+            ));
+        }
+
+        // Watch the input the day will be run on, wherever it comes from.
+        let input = util::input_path(self.day, self.input.as_deref());
+        if input == Path::new(util::STDIN) {
+            return Err("watch can't read the input from stdin".to_string());
+        }
+
+        let mut inotify =
+            Inotify::init().map_err(|err| format!("failed to start inotify: {}", err))?;
+        let mut watches = Watches::add(&inotify, &dir, &input)?;
+
+        println!(
+            "Watching {}/src and {}, press Ctrl-C to stop",
+            name,
+            input.display()
+        );
+
+        let mut previous = None;
+        let mut buffer = [0; 4096];
+        loop {
+            previous = Some(self.cycle(&name, &input, previous.as_deref()));
+
+            loop {
+                let events = inotify
+                    .read_events_blocking(&mut buffer)
+                    .map_err(|err| format!("failed to read inotify events: {}", err))?;
+                // Every event is handled, so that no new directory is missed.
+                let mut changed = false;
+                for e in events {
+                    changed |= watches.handle(&e);
+                }
+                if changed {
+                    break;
+                }
+            }
+
+            // Drain whatever follows closely, it is part of the same change.
+            thread::sleep(SETTLE);
+            while let Ok(events) = inotify.read_events(&mut buffer) {
+                let mut any = false;
+                for e in events {
+                    watches.handle(&e);
+                    any = true;
+                }
+                if !any {
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Builds, runs and tests the day once, returning its answers.
+    fn cycle(
+        &self,
+        name: &str,
+        input: &Path,
+        previous: Option<&[(String, String)]>,
+    ) -> Vec<(String, String)> {
+        println!("\n--- {} ---", name);
+
+        let build = cargo(&["build", "-q", "-p", name]);
+        if !build.status.success() {
+            print!("{}", String::from_utf8_lossy(&build.stderr));
+            println!("Build failed");
+            return previous.map(|p| p.to_vec()).unwrap_or_default();
+        }
+
+        let input = input.to_string_lossy();
+        let run = cargo(&["run", "-q", "-p", name, "--", &input]);
+        let answers = parse_answers(&String::from_utf8_lossy(&run.stdout));
+        if !run.status.success() {
+            print!("{}", String::from_utf8_lossy(&run.stderr));
+        }
+        for line in diff(previous, &answers) {
+            println!("{}", line);
+        }
+
+        let test = cargo(&["test", "-q", "-p", name]);
+        if test.status.success() {
+            println!("Tests passed");
+        } else {
+            print!("{}", String::from_utf8_lossy(&test.stdout));
+            println!("Tests failed");
+        }
+
+        answers
+    }
+}
+
+/// Runs the cargo that runs us, if any, and exits if it can't be started.
+fn cargo(args: &[&str]) -> Output {
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+
+    Command::new(cargo)
+        .args(args)
+        .output()
+        .unwrap_or_else(|err| {
+            eprintln!("error: failed to run cargo: {}", err);
+            exit(1);
+        })
+}
+
+/// The watches on a day's `src/` and the directories under it, including
+/// those created later, and on the directory holding the input, whose
+/// events only matter for the input file. Watching the directory rather
+/// than the file keeps the watch alive when an editor saves by replacing
+/// the file.
+struct Watches {
+    handle: inotify::Watches,
+    sources: Vec<(WatchDescriptor, PathBuf)>,
+    input_dir: WatchDescriptor,
+    input_name: OsString,
+}
+
+impl Watches {
+    const MASK: WatchMask = WatchMask::CLOSE_WRITE
+        .union(WatchMask::MOVED_TO)
+        .union(WatchMask::CREATE)
+        .union(WatchMask::DELETE);
+
+    fn add(inotify: &Inotify, dir: &Path, input: &Path) -> Result<Watches, String> {
+        let input_name = input
+            .file_name()
+            .ok_or(format!("'{}' is not a file", input.display()))?
+            .to_owned();
+        let input_dir = match input.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => parent,
+            _ => Path::new("."),
+        };
+
+        let mut handle = inotify.watches();
+        let input_dir = watch(&mut handle, input_dir)?;
+
+        let mut watches = Watches {
+            handle,
+            sources: Vec::new(),
+            input_dir,
+            input_name,
+        };
+        watches.add_sources(&dir.join("src"))?;
+
+        Ok(watches)
+    }
+
+    /// Watches `dir` and every directory under it for changes to sources.
+    /// Returns whether there are any sources in them already.
+    fn add_sources(&mut self, dir: &Path) -> Result<bool, String> {
+        let mut found = false;
+        let mut pending = vec![dir.to_path_buf()];
+        while let Some(path) = pending.pop() {
+            let wd = watch(&mut self.handle, &path)?;
+
+            let entries = fs::read_dir(&path)
+                .map_err(|err| format!("failed to read '{}': {}", path.display(), err))?;
+            for entry in entries.flatten() {
+                if entry.file_type().is_ok_and(|t| t.is_dir()) {
+                    pending.push(entry.path());
+                } else {
+                    found |= is_source(&entry.file_name());
+                }
+            }
+            self.sources.push((wd, path));
+        }
+
+        Ok(found)
+    }
+
+    /// Takes in an event, watching any directory that appears under the
+    /// sources, and returns whether it is a change to a source file or the
+    /// input, rather than e.g. an editor swap file.
+    fn handle(&mut self, event: &Event<&OsStr>) -> bool {
+        let Some(name) = event.name else {
+            return false;
+        };
+
+        let mut changed = event.wd == self.input_dir && name == self.input_name;
+
+        let source_dir = self.sources.iter().find(|(wd, _)| *wd == event.wd);
+        if let Some((_, dir)) = source_dir {
+            if !event.mask.contains(EventMask::ISDIR) {
+                changed |= is_source(name);
+            } else if event.mask.intersects(EventMask::CREATE | EventMask::MOVED_TO) {
+                // Files may have been written into it before it was watched.
+                let dir = dir.join(name);
+                match self.add_sources(&dir) {
+                    Ok(found) => changed |= found,
+                    Err(err) => eprintln!("error: {}", err),
+                }
+            }
+        }
+
+        changed
+    }
+}
+
+fn watch(handle: &mut inotify::Watches, path: &Path) -> Result<WatchDescriptor, String> {
+    handle
+        .add(path, Watches::MASK)
+        .map_err(|err| format!("failed to watch '{}': {}", path.display(), err))
+}
+
+fn is_source(name: &OsStr) -> bool {
+    name.to_str().is_some_and(|n| n.ends_with(".rs"))
+}
+
+/// The `Part N: answer` lines printed by a day.
+fn parse_answers(output: &str) -> Vec<(String, String)> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let (part, answer) = line.split_once(": ")?;
+            part.starts_with("Part ")
+                .then(|| (part.to_string(), answer.trim().to_string()))
+        })
+        .collect()
+}
+
+/// One line per answer, noting how it compares to the previous run.
+fn diff(previous: Option<&[(String, String)]>, answers: &[(String, String)]) -> Vec<String> {
+    answers
+        .iter()
+        .map(|(part, answer)| {
+            let before = previous
+                .and_then(|p| p.iter().find(|(q, _)| q == part))
+                .map(|(_, a)| a);
+            match before {
+                None if previous.is_none() => format!("{}: {}", part, answer),
+                None => format!("{}: {} (new)", part, answer),
+                Some(b) if b == answer => format!("{}: {} (unchanged)", part, answer),
+                Some(b) => format!("{}: {} (was {})", part, answer, b),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Whether any of the events waiting is a change.
+    fn changed(inotify: &mut Inotify, watches: &mut Watches) -> bool {
+        let mut buffer = [0; 4096];
+        let mut changed = false;
+        while let Ok(events) = inotify.read_events(&mut buffer) {
+            let mut any = false;
+            for e in events {
+                changed |= watches.handle(&e);
+                any = true;
+            }
+            if !any {
+                break;
+            }
+        }
+        changed
+    }
+
+    #[test]
+    fn test_watches() {
+        let root = env::temp_dir().join(format!("aoc-watch-{}", std::process::id()));
+        let dir = root.join("day7");
+        let input = root.join("inputs/day7/input.txt");
+        fs::create_dir_all(dir.join("src")).unwrap();
+        fs::create_dir_all(input.parent().unwrap()).unwrap();
+
+        let mut inotify = Inotify::init().unwrap();
+        let mut watches = Watches::add(&inotify, &dir, &input).unwrap();
+
+        fs::write(&input, "1\n").unwrap();
+        assert!(changed(&mut inotify, &mut watches));
+
+        // Only the input given counts, not the day's own.
+        fs::write(dir.join("input.txt"), "1\n").unwrap();
+        fs::write(input.with_file_name("other.txt"), "1\n").unwrap();
+        assert!(!changed(&mut inotify, &mut watches));
+
+        fs::write(dir.join("src/lib.rs"), "").unwrap();
+        assert!(changed(&mut inotify, &mut watches));
+
+        // A new directory is watched from then on.
+        fs::create_dir(dir.join("src/bin")).unwrap();
+        assert!(!changed(&mut inotify, &mut watches));
+        fs::write(dir.join("src/bin/extra.rs"), "").unwrap();
+        assert!(changed(&mut inotify, &mut watches));
+
+        // Even when sources are written into it before that.
+        fs::create_dir(dir.join("src/more")).unwrap();
+        fs::write(dir.join("src/more/mod.rs"), "").unwrap();
+        assert!(changed(&mut inotify, &mut watches));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_parse_answers() {
+        let answers = parse_answers("Wrote graph.d2\nPart 1: 42\nPart 2: a,b,c\n");
+
+        assert_eq!(
+            answers,
+            vec![
+                ("Part 1".to_string(), "42".to_string()),
+                ("Part 2".to_string(), "a,b,c".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff() {
+        let before = parse_answers("Part 1: 42\n");
+        let after = parse_answers("Part 1: 41\nPart 2: 7\n");
+
+        assert_eq!(diff(None, &before), vec!["Part 1: 42"]);
+        assert_eq!(diff(Some(&before), &before), vec!["Part 1: 42 (unchanged)"]);
+        assert_eq!(
+            diff(Some(&before), &after),
+            vec!["Part 1: 41 (was 42)", "Part 2: 7 (new)"]
+        );
+    }
+}
@@ -0,0 +1,49 @@
+//! Frames of a simulation, for whatever wants to show or record them.
+
+use crate::{
+    image::{Palette, Render},
+    Grid, Pos, Solution,
+};
+
+/// One state of a simulation as it is to be drawn.
+#[derive(Debug, Clone)]
+pub struct Frame {
+    pub grid: Grid<char>,
+    pub palette: Palette,
+    /// The cells of whatever just moved, to draw so they stand out.
+    pub highlight: Vec<Pos>,
+    /// A line about the state, e.g. the step it was taken at.
+    pub caption: String,
+}
+
+impl Frame {
+    pub fn of(state: &impl Render, caption: impl Into<String>) -> Self {
+        Frame {
+            grid: state.render(),
+            palette: state.palette(),
+            highlight: state.highlight(),
+            caption: caption.into(),
+        }
+    }
+}
+
+/// Takes the frames of a simulation as it runs.
+pub trait Sink {
+    /// Takes the next frame, returning whether the simulation should go on.
+    fn push(&mut self, frame: Frame) -> bool;
+}
+
+/// Collects every frame.
+impl Sink for Vec<Frame> {
+    fn push(&mut self, frame: Frame) -> bool {
+        Vec::push(self, frame);
+        true
+    }
+}
+
+/// A day whose solution can be watched as a simulation.
+pub trait Animation: Solution {
+    /// Runs the simulation behind `part` on `input`, pushing a frame into
+    /// `sink` for every step until it is done or `sink` asks to stop.
+    fn animate(input: &Self::Input, part: u8, sink: &mut dyn Sink);
+}
@@ -0,0 +1,268 @@
+use std::{
+    env,
+    error::Error,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+/// Environment variable holding the value of the `session` cookie of a
+/// logged in adventofcode.com account.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Environment variable overriding [`DEFAULT_BASE_URL`].
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+/// Environment variable overriding where downloaded inputs are cached.
+pub const CACHE_DIR_VAR: &str = "AOC_CACHE_DIR";
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+pub const YEAR: u16 = 2024;
+
+/// Sent with every request, as asked for by the site.
+pub const USER_AGENT: &str = "github.com/skaggmannen/aoc-2024";
+
+/// Downloads puzzle inputs and keeps them in a cache directory.
+///
+/// The cache is laid out like the workspace (`dayN/input.txt`), so it can
+/// also be used as `$AOC_INPUT_DIR`. An input that is in the cache is never
+/// requested again.
+pub struct Fetcher {
+    base_url: String,
+    session: Option<String>,
+    cache_dir: PathBuf,
+}
+
+impl Fetcher {
+    pub fn new(base_url: &str, session: Option<String>, cache_dir: PathBuf) -> Self {
+        Fetcher {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+            cache_dir,
+        }
+    }
+
+    /// A fetcher configured from `$AOC_SESSION`, `$AOC_BASE_URL` and
+    /// `$AOC_CACHE_DIR`.
+    pub fn from_env() -> Self {
+        Fetcher::new(&base_url(), session(), cache_dir())
+    }
+
+    /// Where the input for `day` is cached.
+    pub fn cache_path(&self, day: u8) -> PathBuf {
+        self.cache_dir.join(format!("day{}", day)).join("input.txt")
+    }
+
+    /// The input for `day` from the cache, if it has been downloaded.
+    pub fn cached(&self, day: u8) -> Option<String> {
+        fs::read_to_string(self.cache_path(day)).ok()
+    }
+
+    /// The input for `day`, downloaded into the cache unless it is there
+    /// already.
+    pub fn fetch(&self, day: u8) -> Result<String, FetchError> {
+        if let Some(input) = self.cached(day) {
+            return Ok(input);
+        }
+
+        let session = self.session.as_ref().ok_or(FetchError::NoSession)?;
+        let url = format!("{}/{}/day/{}/input", self.base_url, YEAR, day);
+
+        let agent = ureq::AgentBuilder::new()
+            .timeout(Duration::from_secs(30))
+            .user_agent(USER_AGENT)
+            .build();
+        let response = agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", session))
+            .call()
+            .map_err(|err| match err {
+                ureq::Error::Status(status, _) => FetchError::Status {
+                    url: url.clone(),
+                    status,
+                },
+                ureq::Error::Transport(err) => FetchError::Transport {
+                    url: url.clone(),
+                    message: err.to_string(),
+                },
+            })?;
+        let input = response
+            .into_string()
+            .map_err(|err| FetchError::Transport {
+                url: url.clone(),
+                message: err.to_string(),
+            })?;
+
+        self.store(day, &input)?;
+
+        Ok(input)
+    }
+
+    /// Writes through a temporary file, so that an interrupted write never
+    /// leaves a truncated input in the cache.
+    fn store(&self, day: u8, input: &str) -> Result<(), FetchError> {
+        let path = self.cache_path(day);
+        let tmp = path.with_extension("tmp");
+        let io_error = |source| FetchError::Io {
+            path: path.clone(),
+            source,
+        };
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(io_error)?;
+        }
+        fs::write(&tmp, input).map_err(io_error)?;
+        fs::rename(&tmp, &path).map_err(io_error)
+    }
+}
+
+fn var(name: &str) -> Option<String> {
+    env::var(name).ok().filter(|s| !s.trim().is_empty())
+}
+
+/// The session token in `$AOC_SESSION`, if set.
+pub fn session() -> Option<String> {
+    var(SESSION_VAR).map(|s| s.trim().to_string())
+}
+
+/// `$AOC_BASE_URL`, or adventofcode.com.
+pub fn base_url() -> String {
+    var(BASE_URL_VAR).unwrap_or_else(|| DEFAULT_BASE_URL.to_string())
+}
+
+/// `$AOC_CACHE_DIR`, defaulting to `aoc-2024` under `$XDG_CACHE_HOME` or
+/// `~/.cache`.
+pub fn cache_dir() -> PathBuf {
+    match (var(CACHE_DIR_VAR), var("XDG_CACHE_HOME"), var("HOME")) {
+        (Some(dir), _, _) => PathBuf::from(dir),
+        (None, Some(dir), _) => Path::new(&dir).join("aoc-2024"),
+        (None, None, Some(home)) => Path::new(&home).join(".cache").join("aoc-2024"),
+        (None, None, None) => PathBuf::from(".aoc-cache"),
+    }
+}
+
+/// The day of an input path laid out as `dayN/input.txt`, if it is one.
+pub(crate) fn day_of_path(path: &Path) -> Option<u8> {
+    if path.file_name()? != "input.txt" {
+        return None;
+    }
+
+    let dir = path.parent()?.file_name()?.to_str()?;
+    dir.strip_prefix("day")?
+        .parse()
+        .ok()
+        .filter(|day| (1..=25).contains(day))
+}
+
+#[derive(Debug)]
+pub enum FetchError {
+    /// The input isn't cached and there is no session token to download it.
+    NoSession,
+    /// The server answered with an error, e.g. 400 for a stale session or
+    /// 404 for a puzzle that isn't unlocked yet.
+    Status { url: String, status: u16 },
+    /// The server couldn't be reached.
+    Transport { url: String, message: String },
+    /// The cache couldn't be written.
+    Io { path: PathBuf, source: io::Error },
+}
+
+impl Display for FetchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FetchError::NoSession => {
+                write!(f, "the input isn't cached and ${} is not set", SESSION_VAR)
+            }
+            FetchError::Status { url, status } => {
+                write!(f, "failed to download '{}': status {}", url, status)?;
+                match status {
+                    400 => write!(f, " (is ${} still valid?)", SESSION_VAR),
+                    404 => write!(f, " (is the puzzle unlocked?)"),
+                    _ => Ok(()),
+                }
+            }
+            FetchError::Transport { url, message } => {
+                write!(f, "failed to download '{}': {}", url, message)
+            }
+            FetchError::Io { path, source } => write!(f, "'{}': {}", path.display(), source),
+        }
+    }
+}
+
+impl Error for FetchError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            FetchError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock;
+
+    fn cache_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-fetch-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_fetch_once() {
+        let (url, requests) = mock::serve(200, "1 2\n3 4\n");
+        let dir = cache_dir("once");
+        let fetcher = Fetcher::new(&url, Some("abc".to_string()), dir.clone());
+
+        assert_eq!(fetcher.fetch(7).unwrap(), "1 2\n3 4\n");
+        assert_eq!(fetcher.fetch(7).unwrap(), "1 2\n3 4\n");
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].line, "GET /2024/day/7/input HTTP/1.1");
+        assert_eq!(requests[0].cookie, "session=abc");
+        assert_eq!(
+            fs::read_to_string(dir.join("day7/input.txt")).unwrap(),
+            "1 2\n3 4\n"
+        );
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_error_is_not_cached() {
+        let (url, requests) = mock::serve(400, "Please log in");
+        let dir = cache_dir("error");
+        let fetcher = Fetcher::new(&url, Some("stale".to_string()), dir.clone());
+
+        let err = fetcher.fetch(3).unwrap_err();
+        assert!(
+            matches!(err, FetchError::Status { status: 400, .. }),
+            "{}",
+            err
+        );
+        assert!(fetcher.fetch(3).is_err());
+
+        assert_eq!(requests.lock().unwrap().len(), 2);
+        assert!(!fetcher.cache_path(3).exists());
+    }
+
+    #[test]
+    fn test_no_session() {
+        let fetcher = Fetcher::new("http://127.0.0.1:9", None, cache_dir("no-session"));
+
+        assert!(matches!(fetcher.fetch(1), Err(FetchError::NoSession)));
+    }
+
+    #[test]
+    fn test_day_of_path() {
+        assert_eq!(day_of_path(Path::new("day7/input.txt")), Some(7));
+        assert_eq!(day_of_path(Path::new("/inputs/day25/input.txt")), Some(25));
+        assert_eq!(day_of_path(Path::new("day7/example.txt")), None);
+        assert_eq!(day_of_path(Path::new("dayXX/input.txt")), None);
+        assert_eq!(day_of_path(Path::new("input.txt")), None);
+    }
+}
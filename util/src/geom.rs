@@ -0,0 +1,225 @@
+use std::{
+    fmt::Display,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+use crate::Pos;
+
+/// A point, or an offset between two, on a plane where `y` grows downwards
+/// as it does in the puzzle maps.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point { x: 0, y: 0 };
+
+    pub const fn new(x: i32, y: i32) -> Self {
+        Point { x, y }
+    }
+
+    pub fn manhattan(self, other: Point) -> u32 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// The point one step away in `direction`.
+    pub fn step(self, direction: Direction) -> Point {
+        self + direction.delta()
+    }
+
+    /// The four orthogonal neighbours, clockwise from north.
+    pub fn neighbours(self) -> [Point; 4] {
+        Direction::ALL.map(|d| self.step(d))
+    }
+
+    /// All eight neighbours, clockwise from north.
+    pub fn neighbours8(self) -> [Point; 8] {
+        crate::ALL_DIRECTIONS.map(|(dx, dy)| self + Point::new(dx as i32, dy as i32))
+    }
+
+    /// The grid position of the point, if neither coordinate is negative.
+    pub fn to_pos(self) -> Option<Pos> {
+        Some((self.x.try_into().ok()?, self.y.try_into().ok()?))
+    }
+}
+
+impl From<Pos> for Point {
+    fn from((x, y): Pos) -> Self {
+        Point::new(x as i32, y as i32)
+    }
+}
+
+impl Display for Point {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Mul<i32> for Point {
+    type Output = Point;
+
+    fn mul(self, factor: i32) -> Point {
+        Point::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        *self = *self - other;
+    }
+}
+
+/// One of the four directions on a map, with north being up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// Every direction, clockwise from north.
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// Parses an arrow (`^>v<`) or a compass letter (`NESW`).
+    pub fn from_char(c: char) -> Option<Self> {
+        match c {
+            '^' | 'N' => Some(Direction::North),
+            '>' | 'E' => Some(Direction::East),
+            'v' | 'S' => Some(Direction::South),
+            '<' | 'W' => Some(Direction::West),
+            _ => None,
+        }
+    }
+
+    /// The arrow pointing this way.
+    pub fn arrow(self) -> char {
+        match self {
+            Direction::North => '^',
+            Direction::East => '>',
+            Direction::South => 'v',
+            Direction::West => '<',
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        Direction::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Direction::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Self {
+        Direction::ALL[(self as usize + 2) % 4]
+    }
+
+    /// The offset of one step this way.
+    pub fn delta(self) -> Point {
+        match self {
+            Direction::North => Point::new(0, -1),
+            Direction::East => Point::new(1, 0),
+            Direction::South => Point::new(0, 1),
+            Direction::West => Point::new(-1, 0),
+        }
+    }
+
+    /// The offset of one step this way, as taken by [`Grid::step`](crate::Grid::step).
+    pub fn offset(self) -> (isize, isize) {
+        crate::ORTHOGONAL[self as usize]
+    }
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.arrow())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_point() {
+        let a = Point::new(1, 2);
+        let b = Point::new(4, -2);
+
+        assert_eq!(a + b, Point::new(5, 0));
+        assert_eq!(a - b, Point::new(-3, 4));
+        assert_eq!(a * 3, Point::new(3, 6));
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(b.to_pos(), None);
+        assert_eq!(Point::from((3, 4)).to_pos(), Some((3, 4)));
+        assert_eq!(
+            Point::ORIGIN.neighbours(),
+            [
+                Point::new(0, -1),
+                Point::new(1, 0),
+                Point::new(0, 1),
+                Point::new(-1, 0)
+            ]
+        );
+        assert_eq!(Point::ORIGIN.neighbours8()[1], Point::new(1, -1));
+    }
+
+    #[test]
+    fn test_direction() {
+        use Direction::*;
+
+        assert_eq!(North.turn_right(), East);
+        assert_eq!(North.turn_left(), West);
+        assert_eq!(East.reverse(), West);
+        assert_eq!(
+            "^>v<".chars().map(Direction::from_char).collect::<Vec<_>>(),
+            "NESW".chars().map(Direction::from_char).collect::<Vec<_>>()
+        );
+        assert_eq!(Direction::from_char('x'), None);
+        for d in Direction::ALL {
+            assert_eq!(d.delta() + d.reverse().delta(), Point::ORIGIN);
+            assert_eq!(
+                d.delta(),
+                Point::new(d.offset().0 as i32, d.offset().1 as i32)
+            );
+            assert_eq!(Direction::from_char(d.arrow()), Some(d));
+        }
+    }
+}
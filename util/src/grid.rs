@@ -0,0 +1,413 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use crate::{ParseError, Source};
+
+/// A position in a grid as `(x, y)`, with `(0, 0)` in the top left corner.
+pub type Pos = (usize, usize);
+
+/// The four orthogonal steps, clockwise from up.
+pub const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// All eight steps to a neighbouring cell, clockwise from up.
+pub const ALL_DIRECTIONS: [(isize, isize); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+/// A rectangular grid of cells, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A `width` by `height` grid with every cell set to `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// A grid of the given rows, or `None` if they differ in length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let width = rows.first().map_or(0, Vec::len);
+        let height = rows.len();
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+
+        Some(Grid {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Parses the trimmed, non-empty lines of `data` as rows, mapping each
+    /// character with `f`. A character `f` rejects is reported as not being
+    /// `expected`, and every row must be as long as the first.
+    pub fn parse_with<F>(data: &str, expected: &str, mut f: F) -> Result<Self, ParseError>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let source = Source::new(data);
+
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for line in source.lines() {
+            let before = cells.len();
+            for (i, c) in line.char_indices() {
+                cells.push(f(c).ok_or_else(|| source.char_error(line, i, expected))?);
+            }
+
+            let len = cells.len() - before;
+            match width {
+                None => width = Some(len),
+                Some(w) if w != len => {
+                    return Err(source.error(line, format!("a row of {} cells", w)))
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): Pos) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.1 * self.width + pos.0])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self.cells[pos.1 * self.width + pos.0])
+        } else {
+            None
+        }
+    }
+
+    /// The position one `(dx, dy)` step from `pos`, if it is in the grid.
+    pub fn step(&self, (x, y): Pos, (dx, dy): (isize, isize)) -> Option<Pos> {
+        let pos = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+        self.contains(pos).then_some(pos)
+    }
+
+    /// The orthogonal neighbours of `pos` that are in the grid.
+    pub fn neighbours(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        ORTHOGONAL
+            .into_iter()
+            .filter_map(move |d| self.step(pos, d))
+    }
+
+    /// The orthogonal and diagonal neighbours of `pos` that are in the grid.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        ALL_DIRECTIONS
+            .into_iter()
+            .filter_map(move |d| self.step(pos, d))
+    }
+
+    /// The positions from `pos` onwards in steps of `(dx, dy)`, until the
+    /// edge of the grid. Empty if `pos` itself is outside.
+    pub fn ray(&self, pos: Pos, d: (isize, isize)) -> impl Iterator<Item = Pos> + '_ {
+        let first = self.contains(pos).then_some(pos);
+        std::iter::successors(first, move |&p| self.step(p, d))
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| (i % width, i / width))
+    }
+
+    /// Every cell along with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// Every cell, mutably, along with its position, row by row.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Pos, &mut T)> {
+        let width = self.width;
+        self.cells
+            .iter_mut()
+            .enumerate()
+            .map(move |(i, c)| ((i % width, i / width), c))
+    }
+
+    pub fn row(&self, y: usize) -> impl Iterator<Item = &T> {
+        assert!(y < self.height, "row {} out of range for height {}", y, self.height);
+        self.cells[y * self.width..(y + 1) * self.width].iter()
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        // Past the last column the cells would run on into the next row.
+        assert!(x < self.width, "column {} out of range for width {}", x, self.width);
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// The diagonals running down and to the right, starting from the one
+    /// in the bottom left corner.
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let starts = (0..self.height)
+            .rev()
+            .map(|y| (0, y))
+            .chain((1..self.width).map(|x| (x, 0)));
+        starts.map(|pos| self.ray(pos, (1, 1)).map(|p| &self[p]))
+    }
+
+    /// The diagonals running down and to the left, starting from the one in
+    /// the top left corner.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let starts = (0..self.width)
+            .map(|x| (x, 0))
+            .chain((1..self.height).map(|y| (self.width.saturating_sub(1), y)));
+        starts.map(|pos| self.ray(pos, (-1, 1)).map(|p| &self[p]))
+    }
+
+    /// The position of the first cell matching `predicate`, row by row.
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Pos> {
+        self.iter().find(|(_, c)| predicate(c)).map(|(p, _)| p)
+    }
+
+    /// The position of the first cell equal to `value`, row by row.
+    pub fn find(&self, value: &T) -> Option<Pos>
+    where
+        T: PartialEq,
+    {
+        self.position(|c| c == value)
+    }
+
+    /// The positions of every cell equal to `value`, row by row.
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Pos> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(move |(_, c)| *c == value)
+            .map(|(p, _)| p)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// The grid mirrored along its main diagonal, so rows become columns.
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.remap(self.height, self.width, |(x, y)| (y, x))
+    }
+
+    /// The grid turned a quarter clockwise.
+    pub fn rotate_right(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.remap(self.height, self.width, |(x, y)| (y, self.height - 1 - x))
+    }
+
+    /// The grid turned a quarter counter-clockwise.
+    pub fn rotate_left(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.remap(self.height, self.width, |(x, y)| (self.width - 1 - y, x))
+    }
+
+    /// A `width` by `height` grid whose cell at `pos` is ours at `from(pos)`.
+    fn remap(&self, width: usize, height: usize, from: impl Fn(Pos) -> Pos) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let cells = (0..width * height)
+            .map(|i| self[from((i % width, i / width))].clone())
+            .collect();
+
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+}
+
+impl Grid<char> {
+    /// Parses the trimmed, non-empty lines of `data` as rows of characters.
+    pub fn parse(data: &str) -> Result<Self, ParseError> {
+        Grid::parse_with(data, "a character", Some)
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos).unwrap_or_else(|| {
+            panic!(
+                "{:?} is outside the {}x{} grid",
+                pos, self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside the {}x{} grid", pos, width, height))
+    }
+}
+
+/// One row per line, with no separator between cells.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "
+        abc
+        def
+    ";
+
+    fn collect<'a>(lines: impl Iterator<Item = impl Iterator<Item = &'a char>>) -> Vec<String> {
+        lines.map(|l| l.collect()).collect()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = Grid::parse(INPUT).unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 'f');
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.to_string(), "abc\ndef\n");
+
+        let err = Grid::parse("ab\nc").unwrap_err();
+        assert_eq!(err.to_string(), "2:1: expected a row of 2 cells, found 'c'");
+
+        let err = Grid::parse_with("12\n3x", "a digit", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!(err.to_string(), "2:2: expected a digit, found 'x'");
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::parse(INPUT).unwrap();
+
+        assert_eq!(
+            grid.neighbours((0, 0)).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbours8((1, 0)).count(), 5);
+        assert_eq!(grid.step((0, 0), (-1, 0)), None);
+        assert_eq!(
+            grid.ray((0, 0), (1, 1)).collect::<Vec<_>>(),
+            vec![(0, 0), (1, 1)]
+        );
+    }
+
+    #[test]
+    fn test_lines() {
+        let grid = Grid::parse(INPUT).unwrap();
+
+        assert_eq!(grid.row(1).collect::<String>(), "def");
+        assert_eq!(collect(grid.columns()), vec!["ad", "be", "cf"]);
+        assert_eq!(collect(grid.diagonals()), vec!["d", "ae", "bf", "c"]);
+        assert_eq!(collect(grid.anti_diagonals()), vec!["a", "bd", "ce", "f"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "column 3 out of range for width 3")]
+    fn test_column_out_of_range() {
+        let grid = Grid::parse(INPUT).unwrap();
+
+        grid.column(3).count();
+    }
+
+    #[test]
+    #[should_panic(expected = "row 2 out of range for height 2")]
+    fn test_row_out_of_range() {
+        let grid = Grid::parse(INPUT).unwrap();
+
+        grid.row(2).count();
+    }
+
+    #[test]
+    fn test_find() {
+        let grid = Grid::parse("a.b\n.a.").unwrap();
+
+        assert_eq!(grid.find(&'b'), Some((2, 0)));
+        assert_eq!(grid.find(&'c'), None);
+        assert_eq!(
+            grid.find_all(&'a').collect::<Vec<_>>(),
+            vec![(0, 0), (1, 1)]
+        );
+    }
+
+    #[test]
+    fn test_transform() {
+        let grid = Grid::parse(INPUT).unwrap();
+
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotate_right().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_left().to_string(), "cf\nbe\nad\n");
+        assert_eq!(grid.rotate_right().rotate_left(), grid);
+    }
+}
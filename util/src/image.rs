@@ -0,0 +1,205 @@
+//! Pictures of grid states, written as PPM or PNG.
+
+use std::{
+    collections::HashMap,
+    fs::File,
+    hash::Hash,
+    io::{self, BufWriter, Write},
+    path::Path,
+};
+
+use crate::{Grid, Pos};
+
+/// A colour, one byte per channel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+    pub const GRAY: Rgb = Rgb(110, 110, 110);
+    pub const RED: Rgb = Rgb(220, 50, 47);
+    pub const GREEN: Rgb = Rgb(60, 180, 75);
+    pub const BLUE: Rgb = Rgb(38, 139, 210);
+    pub const YELLOW: Rgb = Rgb(230, 190, 30);
+    pub const BROWN: Rgb = Rgb(150, 100, 50);
+}
+
+/// The colour of each kind of cell, and of anything not listed.
+#[derive(Debug, Clone)]
+pub struct Palette<T = char> {
+    colors: HashMap<T, Rgb>,
+    default: Rgb,
+}
+
+impl<T: Eq + Hash> Palette<T> {
+    pub fn new(default: Rgb) -> Self {
+        Palette {
+            colors: HashMap::new(),
+            default,
+        }
+    }
+
+    /// The palette with `cell` drawn in `color`.
+    pub fn with(mut self, cell: T, color: Rgb) -> Self {
+        self.colors.insert(cell, color);
+        self
+    }
+
+    pub fn color(&self, cell: &T) -> Rgb {
+        self.colors.get(cell).copied().unwrap_or(self.default)
+    }
+}
+
+/// A simulation state that can be drawn.
+pub trait Render {
+    /// The state as a grid of the characters the puzzle draws it with.
+    fn render(&self) -> Grid<char>;
+
+    /// The colour of each of those characters in an image.
+    fn palette(&self) -> Palette;
+
+    /// The cells of whatever moves in the state, if anything.
+    fn highlight(&self) -> Vec<Pos> {
+        Vec::new()
+    }
+
+    /// The state as an image, with each cell `scale` pixels wide.
+    fn image(&self, scale: usize) -> Image {
+        Image::from_grid(&self.render(), &self.palette(), scale)
+    }
+
+    /// Writes the state as an image, see [`Image::save`].
+    fn save_image(&self, path: impl AsRef<Path>, scale: usize) -> io::Result<()> {
+        self.image(scale).save(path)
+    }
+}
+
+/// An RGB image.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    /// Draws each cell of `grid` as a `scale` by `scale` square.
+    pub fn from_grid<T: Eq + Hash>(grid: &Grid<T>, palette: &Palette<T>, scale: usize) -> Self {
+        let scale = scale.max(1);
+        let width = grid.width() * scale;
+        let height = grid.height() * scale;
+
+        let pixels = (0..width * height)
+            .map(|i| palette.color(&grid[(i % width / scale, i / width / scale)]))
+            .collect();
+
+        Image {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn pixel(&self, x: usize, y: usize) -> Rgb {
+        self.pixels[y * self.width + x]
+    }
+
+    /// The pixels row by row, three bytes each.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.pixels.iter().flat_map(|&Rgb(r, g, b)| [r, g, b]).collect()
+    }
+
+    /// Writes the image as a binary PPM.
+    pub fn write_ppm<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        write!(w, "P6\n{} {}\n255\n", self.width, self.height)?;
+        w.write_all(&self.to_bytes())
+    }
+
+    pub fn write_png<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        let mut encoder = png::Encoder::new(w, self.width as u32, self.height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        let mut writer = encoder.write_header().map_err(io::Error::other)?;
+        writer
+            .write_image_data(&self.to_bytes())
+            .map_err(io::Error::other)?;
+        writer.finish().map_err(io::Error::other)
+    }
+
+    /// Writes the image to `path` as a PNG or a PPM, going by its extension.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        let extension = path.extension().and_then(|e| e.to_str());
+        if !matches!(extension, Some("png" | "ppm")) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("'{}' is neither a .png nor a .ppm", path.display()),
+            ));
+        }
+
+        let mut w = BufWriter::new(File::create(path)?);
+        match extension {
+            Some("png") => self.write_png(&mut w)?,
+            _ => self.write_ppm(&mut w)?,
+        }
+        w.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn image() -> Image {
+        let grid = Grid::parse("#.\n.@").unwrap();
+        let palette = Palette::new(Rgb::BLACK)
+            .with('#', Rgb::GRAY)
+            .with('@', Rgb::RED);
+
+        Image::from_grid(&grid, &palette, 2)
+    }
+
+    #[test]
+    fn test_from_grid() {
+        let image = image();
+
+        assert_eq!((image.width(), image.height()), (4, 4));
+        assert_eq!(image.pixel(1, 1), Rgb::GRAY);
+        assert_eq!(image.pixel(2, 1), Rgb::BLACK);
+        assert_eq!(image.pixel(3, 3), Rgb::RED);
+    }
+
+    #[test]
+    fn test_ppm() {
+        let mut ppm = Vec::new();
+        image().write_ppm(&mut ppm).unwrap();
+
+        assert!(ppm.starts_with(b"P6\n4 4\n255\n"));
+        assert_eq!(ppm.len(), 11 + 4 * 4 * 3);
+        assert_eq!(&ppm[11..14], &[110, 110, 110]);
+    }
+
+    #[test]
+    fn test_png() {
+        let mut png = Vec::new();
+        image().write_png(&mut png).unwrap();
+
+        let decoder = png::Decoder::new(png.as_slice());
+        let mut reader = decoder.read_info().unwrap();
+        let mut data = vec![0; reader.output_buffer_size()];
+        reader.next_frame(&mut data).unwrap();
+
+        assert_eq!((reader.info().width, reader.info().height), (4, 4));
+        assert_eq!(data, image().to_bytes());
+    }
+}
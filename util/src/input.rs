@@ -0,0 +1,123 @@
+use std::{
+    env,
+    error::Error,
+    ffi::OsString,
+    fmt::Display,
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+use crate::fetch::{day_of_path, FetchError, Fetcher};
+
+/// Environment variable naming a directory laid out like the workspace,
+/// i.e. holding `dayN/input.txt` for each day.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Path that makes [`read_input`] read from stdin.
+pub const STDIN: &str = "-";
+
+/// Resolves where the input for `day` should be read from.
+///
+/// An explicit `arg` always wins (`-` meaning stdin). Otherwise the input is
+/// looked up as `dayN/input.txt` under `$AOC_INPUT_DIR`, falling back to the
+/// current directory.
+pub fn input_path(day: u8, arg: Option<&str>) -> PathBuf {
+    resolve_input_path(day, arg, env::var_os(INPUT_DIR_VAR))
+}
+
+fn resolve_input_path(day: u8, arg: Option<&str>, dir: Option<OsString>) -> PathBuf {
+    if let Some(arg) = arg {
+        return PathBuf::from(arg);
+    }
+
+    let relative = Path::new(&format!("day{}", day)).join("input.txt");
+    match dir {
+        Some(dir) if !dir.is_empty() => PathBuf::from(dir).join(relative),
+        _ => relative,
+    }
+}
+
+/// Reads a puzzle input from `path`, or from stdin if the path is `-`.
+///
+/// A missing `dayN/input.txt` is taken from the download cache instead,
+/// downloading it first if `$AOC_SESSION` is set.
+pub fn read_input<P: AsRef<Path>>(path: P) -> Result<String, InputError> {
+    let path = path.as_ref();
+
+    let result = if path == Path::new(STDIN) {
+        let mut data = String::new();
+        io::stdin().read_to_string(&mut data).map(|_| data)
+    } else {
+        fs::read_to_string(path).or_else(|err| match day_of_path(path) {
+            Some(day) if err.kind() == io::ErrorKind::NotFound => {
+                match Fetcher::from_env().fetch(day) {
+                    Ok(input) => Ok(input),
+                    Err(FetchError::NoSession) => Err(err),
+                    Err(fetch_err) => Err(io::Error::other(fetch_err)),
+                }
+            }
+            _ => Err(err),
+        })
+    };
+
+    result.map_err(|source| InputError {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+/// Failure to read a puzzle input, naming the path that was tried.
+#[derive(Debug)]
+pub struct InputError {
+    pub path: PathBuf,
+    pub source: io::Error,
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.path == Path::new(STDIN) {
+            write!(f, "failed to read input from stdin: {}", self.source)
+        } else {
+            write!(f, "failed to read input '{}': {}", self.path.display(), self.source)
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_explicit_path_wins() {
+        let path = resolve_input_path(7, Some("/tmp/day7.txt"), Some("/inputs".into()));
+
+        assert_eq!(path, PathBuf::from("/tmp/day7.txt"));
+    }
+
+    #[test]
+    fn test_input_dir() {
+        let path = resolve_input_path(7, None, Some("/inputs".into()));
+
+        assert_eq!(path, PathBuf::from("/inputs/day7/input.txt"));
+    }
+
+    #[test]
+    fn test_fallback() {
+        assert_eq!(resolve_input_path(7, None, None), PathBuf::from("day7/input.txt"));
+        assert_eq!(resolve_input_path(7, None, Some("".into())), PathBuf::from("day7/input.txt"));
+    }
+
+    #[test]
+    fn test_error_names_path() {
+        let err = read_input("no/such/day/input.txt").unwrap_err();
+
+        assert!(err.to_string().contains("'no/such/day/input.txt'"), "{}", err);
+    }
+}
@@ -1,4 +1,47 @@
-use std::fs::read_to_string;
+use std::{env, fmt::Display, path::PathBuf, process};
+
+pub mod anim;
+pub mod geom;
+pub mod image;
+#[cfg(any(test, feature = "mock"))]
+pub mod mock;
+pub mod record;
+pub mod search;
+
+mod fetch;
+mod grid;
+mod input;
+mod parse;
+
+pub use fetch::{
+    base_url, cache_dir, session, FetchError, Fetcher, BASE_URL_VAR, CACHE_DIR_VAR, DEFAULT_BASE_URL,
+    SESSION_VAR, USER_AGENT, YEAR,
+};
+pub use grid::{Grid, Pos, ALL_DIRECTIONS, ORTHOGONAL};
+pub use input::{input_path, read_input, InputError, INPUT_DIR_VAR, STDIN};
+pub use parse::{ints, lines, sections, ParseError, Source};
+
+/// A day's puzzle solution.
+///
+/// The input is parsed once by `parse` and then shared by both parts, so
+/// that each phase can be run (and timed) on its own.
+pub trait Solution {
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Self::Answer1;
+    fn part2(input: &Self::Input) -> Self::Answer2;
+}
+
+/// Solves `day` and prints the answers to both parts.
+pub fn run<S: Solution>(day: u8) {
+    let input = load::<S>(day);
+
+    println!("Part 1: {}", S::part1(&input));
+    println!("Part 2: {}", S::part2(&input));
+}
 
 pub fn to_lines(data: &str) -> Vec<String> {
     data.lines()
@@ -7,8 +50,60 @@ pub fn to_lines(data: &str) -> Vec<String> {
         .collect()
 }
 
-pub fn read_input(path: &str) -> String {
-    read_to_string(path).unwrap()
+/// Reads and parses the input for `day`, using the first command line
+/// argument as an explicit path if there is one. Exits the process if the
+/// input can't be read or parsed.
+pub fn load<S: Solution>(day: u8) -> S::Input {
+    let arg = args().input;
+    let path = input_path(day, arg.as_deref());
+
+    let data = read_input(&path).unwrap_or_else(|err| {
+        eprintln!("error: {}", err);
+        process::exit(1);
+    });
+
+    S::parse(&data).unwrap_or_else(|err| {
+        eprintln!("error: {}:{}", path.display(), err);
+        process::exit(1);
+    })
+}
+
+/// Where `--dump PATH` on the command line asks a day's binary to write out
+/// what it can show of its answer, such as day 14's tree as an image.
+pub fn dump_path() -> Option<PathBuf> {
+    args().dump
+}
+
+/// A day binary's command line: an optional input path, and `--dump PATH`.
+#[derive(Debug, Default, PartialEq)]
+struct Args {
+    input: Option<String>,
+    dump: Option<PathBuf>,
+}
+
+fn args() -> Args {
+    parse_args(env::args().skip(1)).unwrap_or_else(|msg| {
+        eprintln!("error: {}", msg);
+        process::exit(2);
+    })
+}
+
+fn parse_args(args: impl Iterator<Item = String>) -> Result<Args, String> {
+    let mut result = Args::default();
+
+    let mut args = args;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--dump" => {
+                let path = args.next().ok_or("missing path after --dump")?;
+                result.dump = Some(path.into());
+            }
+            _ if result.input.is_none() => result.input = Some(arg),
+            _ => return Err(format!("unexpected argument '{}'", arg)),
+        }
+    }
+
+    Ok(result)
 }
 
 pub fn trim_space(s: &str) -> Option<&str> {
@@ -18,4 +113,25 @@ pub fn trim_space(s: &str) -> Option<&str> {
     } else {
         Some(trimmed)
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Args, String> {
+        parse_args(args.iter().map(|s| s.to_string()))
+    }
+
+    #[test]
+    fn test_parse_args() {
+        assert_eq!(parse(&[]), Ok(Args::default()));
+        assert_eq!(
+            parse(&["--dump", "tree.png", "-"]),
+            Ok(Args { input: Some("-".to_string()), dump: Some("tree.png".into()) })
+        );
+        assert_eq!(parse(&["in.txt"]).unwrap().input.as_deref(), Some("in.txt"));
+        assert_eq!(parse(&["in.txt", "--dump"]), Err("missing path after --dump".to_string()));
+        assert_eq!(parse(&["a", "b"]), Err("unexpected argument 'b'".to_string()));
+    }
+}
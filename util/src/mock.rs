@@ -0,0 +1,70 @@
+//! A local HTTP server standing in for the puzzle site in tests.
+
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    net::TcpListener,
+    sync::{Arc, Mutex},
+    thread,
+};
+
+/// A request the server was sent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request {
+    /// The request line, e.g. `GET /2024/day/7/input HTTP/1.1`.
+    pub line: String,
+    /// The value of the `Cookie` header, or empty if there was none.
+    pub cookie: String,
+    pub body: String,
+}
+
+/// Answers every request with `status` and `body` on a local port. Returns
+/// the server's URL and the requests it has been sent so far.
+pub fn serve(status: u16, body: &'static str) -> (String, Arc<Mutex<Vec<Request>>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let requests = Arc::new(Mutex::new(Vec::new()));
+
+    let log = requests.clone();
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            let mut cookie = String::new();
+            let mut length = 0;
+            loop {
+                let mut header = String::new();
+                reader.read_line(&mut header).unwrap();
+                if header.trim().is_empty() {
+                    break;
+                }
+                let (name, value) = header.split_once(':').unwrap_or((&header, ""));
+                match name.to_lowercase().as_str() {
+                    "cookie" => cookie = value.trim().to_string(),
+                    "content-length" => length = value.trim().parse().unwrap(),
+                    _ => {}
+                }
+            }
+            let mut request_body = vec![0; length];
+            reader.read_exact(&mut request_body).unwrap();
+            log.lock().unwrap().push(Request {
+                line: line.trim().to_string(),
+                cookie,
+                body: String::from_utf8(request_body).unwrap(),
+            });
+
+            write!(
+                stream,
+                "HTTP/1.1 {} X\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            )
+            .unwrap();
+        }
+    });
+
+    (url, requests)
+}
@@ -0,0 +1,297 @@
+use std::{error::Error, fmt::Display, str::FromStr};
+
+/// A malformed token in a puzzle input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line of the offending text.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    /// The text that could not be parsed, empty if something was missing.
+    pub text: String,
+    /// What the parser was looking for, e.g. "a number" or "'->'".
+    pub expected: String,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}: expected {}, ", self.line, self.column, self.expected)?;
+        if self.text.is_empty() {
+            write!(f, "found nothing")
+        } else {
+            write!(f, "found '{}'", self.text)
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// The full text of a puzzle input.
+///
+/// Parsers should only ever work on slices borrowed from the source, so that
+/// an error for any token can be traced back to its line and column.
+#[derive(Clone, Copy)]
+pub struct Source<'a> {
+    data: &'a str,
+}
+
+impl<'a> Source<'a> {
+    pub fn new(data: &'a str) -> Self {
+        Source { data }
+    }
+
+    /// The trimmed, non-empty lines of the source.
+    pub fn lines(&self) -> impl Iterator<Item = &'a str> {
+        lines(self.data)
+    }
+
+    /// Builds an error for `text`, which must be a slice of the source to get
+    /// a meaningful position. Anything else is reported at the end of input.
+    pub fn error(&self, text: &str, expected: impl Into<String>) -> ParseError {
+        let start = self.data.as_ptr() as usize;
+        let offset = (text.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|&offset| offset <= self.data.len())
+            .unwrap_or(self.data.len());
+
+        let before = &self.data[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+
+        ParseError {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            text: text.to_string(),
+            expected: expected.into(),
+        }
+    }
+
+    /// Builds an error for the character starting at byte `index` of `s`.
+    pub fn char_error(&self, s: &str, index: usize, expected: impl Into<String>) -> ParseError {
+        let len = s[index..].chars().next().map_or(0, char::len_utf8);
+        self.error(&s[index..index + len], expected)
+    }
+
+    /// Builds an error for something missing right after `text`.
+    pub fn missing_after(&self, text: &str, expected: impl Into<String>) -> ParseError {
+        self.error(&text[text.len()..], expected)
+    }
+
+    /// Parses `token`, naming `expected` if it isn't a valid `T`.
+    pub fn parse<T: FromStr>(&self, token: &str, expected: &str) -> Result<T, ParseError> {
+        token.trim().parse().map_err(|_| self.error(token, expected))
+    }
+
+    /// Splits `s` on the first occurrence of `delimiter`.
+    pub fn split_once(&self, s: &'a str, delimiter: &str) -> Result<(&'a str, &'a str), ParseError> {
+        s.split_once(delimiter)
+            .ok_or_else(|| self.error(s, format!("'{}'", delimiter)))
+    }
+
+    /// Strips `prefix` from the start of `s`.
+    pub fn strip_prefix(&self, s: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+        s.strip_prefix(prefix)
+            .ok_or_else(|| self.error(s, format!("'{}'", prefix)))
+    }
+
+    /// Takes the next item from `iter`, reporting it missing after `prev`.
+    pub fn next<I: Iterator<Item = &'a str>>(
+        &self,
+        iter: &mut I,
+        prev: &str,
+        expected: &str,
+    ) -> Result<&'a str, ParseError> {
+        iter.next().ok_or_else(|| self.missing_after(prev, expected))
+    }
+
+    /// The blank-line-separated sections of the source, trimmed.
+    pub fn sections(&self) -> impl Iterator<Item = &'a str> {
+        sections(self.data)
+    }
+
+    /// Splits the source into exactly two sections, naming what should
+    /// follow the blank line if there is only one.
+    pub fn two_sections(&self, second: &str) -> Result<(&'a str, &'a str), ParseError> {
+        let mut sections = self.sections();
+        let first = sections.next().unwrap_or(&self.data[self.data.len()..]);
+        let expected = format!("a blank line followed by {}", second);
+        let rest = self.next(&mut sections, first, &expected)?;
+        match sections.next() {
+            Some(extra) => Err(self.error(extra, "the end of input")),
+            None => Ok((first, rest)),
+        }
+    }
+
+    /// Splits `s` into exactly `N` trimmed fields separated by `delimiter`,
+    /// e.g. a `key: value` or an `a|b` record.
+    pub fn record<const N: usize>(
+        &self,
+        s: &'a str,
+        delimiter: &str,
+    ) -> Result<[&'a str; N], ParseError> {
+        let mut fields = [""; N];
+        let mut rest = s;
+        for field in fields.iter_mut().take(N.saturating_sub(1)) {
+            let (head, tail) = self.split_once(rest, delimiter)?;
+            *field = head.trim();
+            rest = tail;
+        }
+
+        if let Some(i) = rest.find(delimiter) {
+            let expected = format!("{} fields separated by '{}'", N, delimiter);
+            return Err(self.error(&rest[i..], expected));
+        }
+        if let Some(last) = fields.last_mut() {
+            *last = rest.trim();
+        }
+
+        Ok(fields)
+    }
+
+    /// Parses every signed integer in `s`, ignoring whatever is around them.
+    pub fn ints<T: FromStr>(&self, s: &'a str) -> Result<Vec<T>, ParseError> {
+        ints(s).map(|token| self.parse(token, "a number that fits")).collect()
+    }
+
+    /// Parses every trimmed, non-empty line with `f`.
+    pub fn parse_lines<T, F>(&self, f: F) -> Result<Vec<T>, ParseError>
+    where
+        F: FnMut(&'a str) -> Result<T, ParseError>,
+    {
+        self.lines().map(f).collect()
+    }
+}
+
+/// The trimmed, non-empty lines of `data`, borrowed from it.
+pub fn lines(data: &str) -> impl Iterator<Item = &str> {
+    data.lines().map(|s| s.trim()).filter(|s| !s.is_empty())
+}
+
+/// The blank-line-separated sections of `data`, trimmed and borrowed from
+/// it. Lines holding only whitespace count as blank, as they do in the
+/// indented test inputs.
+pub fn sections(data: &str) -> impl Iterator<Item = &str> {
+    let mut sections = Vec::new();
+    let mut start = None;
+    let mut end = 0;
+    let mut offset = 0;
+
+    for line in data.split_inclusive('\n') {
+        let content = line.trim();
+        if content.is_empty() {
+            if let Some(s) = start.take() {
+                sections.push(&data[s..end]);
+            }
+        } else {
+            let indent = line.len() - line.trim_start().len();
+            start.get_or_insert(offset + indent);
+            end = offset + indent + content.len();
+        }
+        offset += line.len();
+    }
+    if let Some(s) = start {
+        sections.push(&data[s..end]);
+    }
+
+    sections.into_iter()
+}
+
+/// The signed integers in `s`, as slices of it. A '-' only counts as a sign
+/// when a digit follows it.
+pub fn ints(s: &str) -> impl Iterator<Item = &str> {
+    let bytes = s.as_bytes();
+    let mut i = 0;
+
+    std::iter::from_fn(move || {
+        while i < bytes.len() {
+            let start = i;
+            if bytes[i] == b'-' && bytes.get(i + 1).is_some_and(u8::is_ascii_digit) {
+                i += 1;
+            }
+            if bytes[i].is_ascii_digit() {
+                while i < bytes.len() && bytes[i].is_ascii_digit() {
+                    i += 1;
+                }
+                return Some(&s[start..i]);
+            }
+            i = start + 1;
+        }
+        None
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_position() {
+        let data = "
+            1 2
+            3 x 4
+        ";
+        let source = Source::new(data);
+
+        let token = source.lines().nth(1).unwrap().split(' ').nth(1).unwrap();
+        let err = source.parse::<i32>(token, "a number").unwrap_err();
+
+        assert_eq!(err.line, 3);
+        assert_eq!(err.column, 15);
+        assert_eq!(err.to_string(), "3:15: expected a number, found 'x'");
+    }
+
+    #[test]
+    fn test_missing_after() {
+        let source = Source::new("a -> b\nc");
+
+        let line = source.lines().nth(1).unwrap();
+        let err = source.split_once(line, " -> ").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+
+        let err = source.missing_after(line, "'->'");
+        assert_eq!((err.line, err.column), (2, 2));
+        assert_eq!(err.to_string(), "2:2: expected '->', found nothing");
+    }
+
+    #[test]
+    fn test_sections() {
+        // The blank line between 'b' and 'c' holds spaces.
+        let data = "a\nb\n    \nc\n\n  d\n";
+        let source = Source::new(data);
+
+        let sections: Vec<_> = source.sections().collect();
+        assert_eq!(sections.len(), 3);
+        assert_eq!(sections, vec!["a\nb", "c", "d"]);
+
+        let err = source.two_sections("updates").unwrap_err();
+        assert_eq!(err.to_string(), "6:3: expected the end of input, found 'd'");
+
+        let err = Source::new("a\nb\n").two_sections("updates").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "2:2: expected a blank line followed by updates, found nothing"
+        );
+    }
+
+    #[test]
+    fn test_record() {
+        let source = Source::new("x00: 1\n47|53|61");
+
+        assert_eq!(source.record::<2>("x00: 1", ": ").unwrap(), ["x00", "1"]);
+
+        let line = source.lines().nth(1).unwrap();
+        assert_eq!(source.record::<3>(line, "|").unwrap(), ["47", "53", "61"]);
+        let err = source.record::<2>(line, "|").unwrap_err();
+        assert_eq!(err.to_string(), "2:6: expected 2 fields separated by '|', found '|61'");
+    }
+
+    #[test]
+    fn test_ints() {
+        let source = Source::new("p=0,-4 v=3,-3 x-y 99999999999");
+
+        assert_eq!(ints("p=0,-4 v=3,-3 x-y").collect::<Vec<_>>(), vec!["0", "-4", "3", "-3"]);
+        assert_eq!(source.ints::<i64>("Button A: X+94, Y+34").unwrap(), vec![94, 34]);
+
+        let err = source.ints::<i32>(source.lines().next().unwrap()).unwrap_err();
+        assert_eq!(err.to_string(), "1:19: expected a number that fits, found '99999999999'");
+    }
+}
@@ -0,0 +1,245 @@
+//! Animated GIFs of simulations.
+
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+    fs::File,
+    io::{self, BufWriter, Write},
+    mem,
+    path::Path,
+    time::Duration,
+};
+
+use gif::{Encoder, Repeat};
+
+use crate::{
+    anim::{Frame, Sink},
+    image::Rgb,
+};
+
+/// Encodes the frames pushed into it as an animated GIF.
+///
+/// Only every `skip + 1`th frame is kept, and the recording stops, asking
+/// the simulation to do the same, once `max_frames` have been kept.
+/// Highlighted cells are drawn in inverted colours.
+pub struct Recorder<W: Write> {
+    state: State<W>,
+    scale: usize,
+    delay: Duration,
+    skip: usize,
+    max_frames: Option<usize>,
+    pushed: usize,
+    written: usize,
+}
+
+enum State<W: Write> {
+    /// Waiting for the first frame, which decides the size of the image.
+    Ready(W),
+    Recording(Encoder<W>),
+    Failed(io::Error),
+    Finished,
+}
+
+impl Recorder<BufWriter<File>> {
+    pub fn create(path: impl AsRef<Path>) -> io::Result<Self> {
+        Ok(Recorder::new(BufWriter::new(File::create(path)?)))
+    }
+}
+
+impl<W: Write> Recorder<W> {
+    pub fn new(w: W) -> Self {
+        Recorder {
+            state: State::Ready(w),
+            scale: 4,
+            delay: Duration::from_millis(100),
+            skip: 0,
+            max_frames: None,
+            pushed: 0,
+            written: 0,
+        }
+    }
+
+    /// Draws each cell as a `scale` by `scale` square, 4 by default.
+    pub fn scale(mut self, scale: usize) -> Self {
+        self.scale = scale.max(1);
+        self
+    }
+
+    /// Shows each frame for `delay`, rounded to the hundredths of a second
+    /// GIFs count in. 100ms by default.
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Keeps one frame and then drops `skip`, over and over.
+    pub fn skip(mut self, skip: usize) -> Self {
+        self.skip = skip;
+        self
+    }
+
+    pub fn max_frames(mut self, max_frames: usize) -> Self {
+        self.max_frames = Some(max_frames);
+        self
+    }
+
+    /// Ends the animation, returning the number of frames in it.
+    pub fn finish(mut self) -> io::Result<usize> {
+        match mem::replace(&mut self.state, State::Finished) {
+            State::Recording(encoder) => encoder.into_inner()?.flush()?,
+            State::Failed(err) => return Err(err),
+            State::Ready(_) => {
+                return Err(io::Error::new(io::ErrorKind::InvalidInput, "nothing to record"))
+            }
+            State::Finished => {}
+        }
+
+        Ok(self.written)
+    }
+
+    fn write(&mut self, frame: &Frame) -> io::Result<()> {
+        let width = frame.grid.width() * self.scale;
+        let height = frame.grid.height() * self.scale;
+        let (width, height) = match (u16::try_from(width), u16::try_from(height)) {
+            (Ok(w), Ok(h)) => (w, h),
+            _ => return Err(io::Error::other(format!("{}x{} is too big for a GIF", width, height))),
+        };
+
+        if let State::Ready(_) = self.state {
+            let State::Ready(w) = mem::replace(&mut self.state, State::Finished) else {
+                unreachable!();
+            };
+            let mut encoder = Encoder::new(w, width, height, &[]).map_err(io::Error::other)?;
+            encoder.set_repeat(Repeat::Infinite).map_err(io::Error::other)?;
+            self.state = State::Recording(encoder);
+        }
+        let State::Recording(encoder) = &mut self.state else {
+            unreachable!();
+        };
+
+        let (palette, buffer) = indexed(frame, self.scale)?;
+        let frame = gif::Frame {
+            width,
+            height,
+            delay: (self.delay.as_millis() / 10).min(u16::MAX as u128) as u16,
+            palette: Some(palette),
+            buffer: Cow::Owned(buffer),
+            ..gif::Frame::default()
+        };
+        encoder.write_frame(&frame).map_err(io::Error::other)
+    }
+}
+
+impl<W: Write> Sink for Recorder<W> {
+    fn push(&mut self, frame: Frame) -> bool {
+        if !matches!(self.state, State::Ready(_) | State::Recording(_)) {
+            return false;
+        }
+
+        let keep = self.pushed.is_multiple_of(self.skip + 1);
+        self.pushed += 1;
+        if keep {
+            if let Err(err) = self.write(&frame) {
+                self.state = State::Failed(err);
+                return false;
+            }
+            self.written += 1;
+        }
+
+        self.max_frames.is_none_or(|max| self.written < max)
+    }
+}
+
+/// The colours used by `frame`, three bytes each, and the index into them
+/// of every pixel of the frame drawn at `scale`.
+fn indexed(frame: &Frame, scale: usize) -> io::Result<(Vec<u8>, Vec<u8>)> {
+    let highlight: HashSet<_> = frame.highlight.iter().collect();
+    let mut colors: HashMap<Rgb, u8> = HashMap::new();
+    let mut palette = Vec::new();
+
+    let mut cells = Vec::with_capacity(frame.grid.width() * frame.grid.height());
+    for (pos, c) in frame.grid.iter() {
+        let Rgb(r, g, b) = frame.palette.color(c);
+        let color = if highlight.contains(&pos) {
+            Rgb(255 - r, 255 - g, 255 - b)
+        } else {
+            Rgb(r, g, b)
+        };
+
+        let index = match colors.get(&color) {
+            Some(&i) => i,
+            None => {
+                let i = u8::try_from(colors.len())
+                    .map_err(|_| io::Error::other("a GIF frame can't have over 256 colours"))?;
+                colors.insert(color, i);
+                palette.extend([color.0, color.1, color.2]);
+                i
+            }
+        };
+        cells.push(index);
+    }
+
+    let width = frame.grid.width();
+    let buffer = (0..frame.grid.height() * scale)
+        .flat_map(|y| (0..width * scale).map(move |x| (x / scale, y / scale)))
+        .map(|(x, y)| cells[y * width + x])
+        .collect();
+
+    Ok((palette, buffer))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{image::Palette, Grid};
+
+    fn frame(robot: usize) -> Frame {
+        let mut grid = Grid::parse("#...#").unwrap();
+        grid[(robot, 0)] = '@';
+
+        Frame {
+            grid,
+            palette: Palette::new(Rgb::BLACK)
+                .with('#', Rgb::GRAY)
+                .with('@', Rgb::RED),
+            highlight: vec![(robot, 0)],
+            caption: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_record() {
+        let mut recorder = Recorder::new(Vec::new()).scale(2).skip(1).max_frames(2);
+
+        assert!(recorder.push(frame(1)));
+        assert!(recorder.push(frame(2)));
+        assert!(!recorder.push(frame(3)));
+        assert_eq!(recorder.written, 2);
+
+        let State::Recording(encoder) = recorder.state else {
+            panic!("not recording");
+        };
+        let gif = encoder.into_inner().unwrap();
+
+        let mut decoder = gif::DecodeOptions::new();
+        decoder.set_color_output(gif::ColorOutput::RGBA);
+        let mut decoder = decoder.read_info(gif.as_slice()).unwrap();
+        assert_eq!((decoder.width(), decoder.height()), (10, 2));
+
+        let mut robots = Vec::new();
+        while let Some(frame) = decoder.read_next_frame().unwrap() {
+            // The robot is highlighted, so red turns cyan.
+            let x = frame.buffer.chunks(4).position(|p| p == [35, 205, 208, 255]);
+            robots.push(x.map(|x| x / 2));
+        }
+        assert_eq!(robots, vec![Some(1), Some(3)]);
+    }
+
+    #[test]
+    fn test_indexed() {
+        let (palette, buffer) = indexed(&frame(1), 1).unwrap();
+
+        assert_eq!(palette, vec![110, 110, 110, 35, 205, 208, 0, 0, 0]);
+        assert_eq!(buffer, vec![0, 1, 2, 2, 0]);
+    }
+}
@@ -0,0 +1,313 @@
+//! Shortest path searches over any state type.
+//!
+//! Each search starts from one state and asks a closure for the states that
+//! follow each one it reaches. It stops at the first state that `is_goal`
+//! accepts, but only after settling every other state that is no further
+//! away, so that all tied goals and all their optimal predecessors are
+//! known. Pass `|_| false` to explore everything reachable.
+
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+};
+
+/// The outcome of a search: how far every settled state is from the start,
+/// and every state that precedes it on some shortest path.
+#[derive(Debug, Clone)]
+pub struct Search<S> {
+    pub distances: HashMap<S, usize>,
+    pub predecessors: HashMap<S, Vec<S>>,
+    /// The first goal reached, if any.
+    pub goal: Option<S>,
+}
+
+impl<S: Clone + Eq + Hash> Search<S> {
+    fn new(start: S) -> Self {
+        Search {
+            distances: HashMap::from([(start, 0)]),
+            predecessors: HashMap::new(),
+            goal: None,
+        }
+    }
+
+    pub fn distance(&self, state: &S) -> Option<usize> {
+        self.distances.get(state).copied()
+    }
+
+    /// The distance to the goal, if one was reached.
+    pub fn goal_distance(&self) -> Option<usize> {
+        self.goal.as_ref().and_then(|g| self.distance(g))
+    }
+
+    /// One shortest path from the start to `to`, both included.
+    pub fn path(&self, to: &S) -> Option<Vec<S>> {
+        self.distances.get(to)?;
+
+        let mut path = vec![to.clone()];
+        while let Some(prev) = self.predecessors.get(path.last().unwrap()) {
+            path.push(prev[0].clone());
+        }
+        path.reverse();
+
+        Some(path)
+    }
+
+    /// Every state on any shortest path from the start to any of `targets`.
+    pub fn on_paths(&self, targets: impl IntoIterator<Item = S>) -> HashSet<S> {
+        let mut seen = HashSet::new();
+        let mut pending: Vec<S> = targets
+            .into_iter()
+            .filter(|t| self.distances.contains_key(t))
+            .collect();
+
+        while let Some(state) = pending.pop() {
+            if let Some(prev) = self.predecessors.get(&state) {
+                pending.extend(prev.iter().filter(|p| !seen.contains(*p)).cloned());
+            }
+            seen.insert(state);
+        }
+
+        seen
+    }
+
+    /// The number of distinct shortest paths from the start to `to`, or
+    /// `None` if there is no end to them, because steps that cost nothing
+    /// lead round in a cycle on the way.
+    pub fn count_paths(&self, to: &S) -> Option<u64> {
+        let mut pending: Vec<S> = self.on_paths([to.clone()]).into_iter().collect();
+        pending.sort_by_key(|s| self.distances[s]);
+
+        // States the same distance away, joined by steps that cost nothing,
+        // may need more than one pass to count in order.
+        let mut counts: HashMap<S, u64> = HashMap::new();
+        while !pending.is_empty() {
+            let before = pending.len();
+            pending.retain(|state| {
+                let count = match self.predecessors.get(state) {
+                    Some(prev) => prev.iter().map(|p| counts.get(p).copied()).sum(),
+                    None => Some(1),
+                };
+                if let Some(count) = count {
+                    counts.insert(state.clone(), count);
+                }
+                count.is_none()
+            });
+            // What is left all waits on itself, round a cycle.
+            if pending.len() == before {
+                return None;
+            }
+        }
+
+        Some(counts.get(to).copied().unwrap_or(0))
+    }
+
+    /// Records `next` as reached from `from` at `distance`. Returns whether
+    /// that is closer than it was known to be before.
+    fn relax(&mut self, from: &S, next: S, distance: usize) -> bool {
+        match self.distances.get(&next) {
+            Some(&d) if distance > d => false,
+            Some(&d) if distance == d => {
+                // Every state reached has predecessors but the start, which
+                // a step costing nothing may lead back into.
+                if let Some(prev) = self.predecessors.get_mut(&next) {
+                    if !prev.contains(from) {
+                        prev.push(from.clone());
+                    }
+                }
+                false
+            }
+            _ => {
+                self.distances.insert(next.clone(), distance);
+                self.predecessors.insert(next, vec![from.clone()]);
+                true
+            }
+        }
+    }
+}
+
+/// Breadth-first search, where every step costs one.
+pub fn bfs<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut search = Search::new(start.clone());
+    let mut queue = VecDeque::from([(start, 0)]);
+
+    while let Some((state, distance)) = queue.pop_front() {
+        if search.goal_distance().is_some_and(|g| distance > g) {
+            break;
+        }
+        if search.goal.is_none() && is_goal(&state) {
+            search.goal = Some(state.clone());
+        }
+
+        for next in successors(&state) {
+            if search.relax(&state, next.clone(), distance + 1) {
+                queue.push_back((next, distance + 1));
+            }
+        }
+    }
+
+    search
+}
+
+/// Dijkstra's algorithm, where `successors` gives each next state along
+/// with the cost of the step to it.
+pub fn dijkstra<S, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    astar(start, successors, |_| 0, is_goal)
+}
+
+/// A* search, which is Dijkstra's algorithm guided towards the goal by
+/// `heuristic`. The heuristic must never overestimate the distance left,
+/// and must not drop by more than the cost of any step.
+pub fn astar<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> usize,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    let mut search = Search::new(start.clone());
+    let mut settled = HashSet::new();
+
+    // States are kept aside so that the heap doesn't need them to be `Ord`.
+    let mut states = vec![start];
+    let mut heap = BinaryHeap::from([Reverse((heuristic(&states[0]), 0, 0))]);
+
+    while let Some(Reverse((estimate, distance, i))) = heap.pop() {
+        if search.goal_distance().is_some_and(|g| estimate > g) {
+            break;
+        }
+        let state = states[i].clone();
+        if distance > search.distances[&state] || !settled.insert(state.clone()) {
+            continue;
+        }
+        if search.goal.is_none() && is_goal(&state) {
+            search.goal = Some(state.clone());
+        }
+
+        for (next, cost) in successors(&state) {
+            let next_distance = distance + cost;
+            if search.relax(&state, next.clone(), next_distance) {
+                heap.push(Reverse((
+                    next_distance + heuristic(&next),
+                    next_distance,
+                    states.len(),
+                )));
+                states.push(next);
+            }
+        }
+    }
+
+    search
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Moves right or down on a `size` by `size` grid.
+    fn lattice(size: i32) -> impl Fn(&(i32, i32)) -> Vec<(i32, i32)> {
+        move |&(x, y)| {
+            [(x + 1, y), (x, y + 1)]
+                .into_iter()
+                .filter(|&(x, y)| x < size && y < size)
+                .collect()
+        }
+    }
+
+    #[test]
+    fn test_bfs() {
+        let search = bfs((0, 0), lattice(3), |&p| p == (2, 2));
+
+        assert_eq!(search.goal, Some((2, 2)));
+        assert_eq!(search.goal_distance(), Some(4));
+        assert_eq!(search.path(&(2, 2)).unwrap().len(), 5);
+        assert_eq!(search.count_paths(&(2, 2)), Some(6));
+        assert_eq!(search.on_paths([(2, 0)]).len(), 3);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        // The direct edge is dearer than going around through 'b' or 'c'.
+        let edges = |s: &char| match s {
+            'a' => vec![('b', 1), ('c', 2), ('d', 5)],
+            'b' => vec![('d', 3)],
+            'c' => vec![('d', 2)],
+            _ => vec![],
+        };
+
+        let search = dijkstra('a', edges, |&s| s == 'd');
+
+        assert_eq!(search.goal_distance(), Some(4));
+        assert_eq!(search.predecessors[&'d'], vec!['b', 'c']);
+        assert_eq!(search.count_paths(&'d'), Some(2));
+        assert_eq!(search.on_paths(['d']).len(), 4);
+
+        let unreachable = dijkstra('b', edges, |&s| s == 'a');
+        assert_eq!(unreachable.goal, None);
+        assert_eq!(unreachable.distance(&'d'), Some(3));
+    }
+
+    #[test]
+    fn test_zero_cost_cycle() {
+        let edges = |s: &char| match s {
+            'a' => vec![('b', 0)],
+            'b' => vec![('a', 0), ('c', 1)],
+            _ => vec![],
+        };
+
+        let search = dijkstra('a', edges, |_| false);
+
+        assert_eq!(search.path(&'a'), Some(vec!['a']));
+        assert_eq!(search.path(&'c'), Some(vec!['a', 'b', 'c']));
+        assert_eq!(search.count_paths(&'c'), Some(1));
+    }
+
+    #[test]
+    fn test_zero_cost_cycle_on_path() {
+        // 'b' and 'c' can go back and forth between each other for free
+        // any number of times on the way to 'd'.
+        let edges = |s: &char| match s {
+            'a' => vec![('b', 1), ('e', 1)],
+            'b' => vec![('c', 0)],
+            'c' => vec![('b', 0), ('d', 1)],
+            _ => vec![],
+        };
+
+        let search = dijkstra('a', edges, |_| false);
+
+        assert_eq!(search.path(&'d'), Some(vec!['a', 'b', 'c', 'd']));
+        assert_eq!(search.count_paths(&'d'), None);
+        assert_eq!(search.count_paths(&'e'), Some(1));
+        assert_eq!(search.count_paths(&'f'), Some(0));
+    }
+
+    #[test]
+    fn test_astar() {
+        let successors = |&p: &(i32, i32)| lattice(10)(&p).into_iter().map(|n| (n, 1));
+        let heuristic = |&(x, y): &(i32, i32)| (18 - x - y) as usize;
+
+        let search = astar((0, 0), successors, heuristic, |&p| p == (9, 9));
+
+        assert_eq!(search.goal_distance(), Some(18));
+        assert_eq!(search.path(&(9, 9)).unwrap().first(), Some(&(0, 0)));
+    }
+}